@@ -0,0 +1,130 @@
+use aoc_2025_common::{Input, Part};
+use std::error::Error;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use crate::runner;
+
+/// One line of the answers manifest: the answer a day should give for one part of an input
+#[derive(Clone, Debug)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+#[derive(Debug)]
+pub enum ManifestError {
+    FailedToRead { path: PathBuf, error: std::io::Error },
+    Malformed { line: usize, text: String },
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FailedToRead { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Malformed { line, text } => write!(f, "line {line} isn't <day> <part> <input> <answer>: {text:?}"),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::FailedToRead { error, .. } => Some(error),
+            Self::Malformed { .. } => None,
+        }
+    }
+}
+
+pub enum Outcome {
+    Pass,
+    Fail { actual: String },
+    NoSolution,
+    NoInput,
+    InvalidInput(String),
+    Unsolved(String),
+    Panicked,
+}
+
+/// Reads a manifest of expected answers. Each line is `<day> <part> <input> <answer>`, where the input path is
+/// relative to the manifest. Blank lines and lines starting with `#` are ignored
+pub fn load_manifest(path: &Path) -> Result<Box<[ExpectedAnswer]>, ManifestError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| ManifestError::FailedToRead { path: path.to_path_buf(), error })?;
+
+    let directory = path.parent().unwrap_or(Path::new(""));
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(index, line)| {
+            let malformed = || ManifestError::Malformed { line: index + 1, text: line.to_string() };
+
+            let [day, part, input, answer] = line.split_whitespace()
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| malformed())?;
+
+            Ok(ExpectedAnswer {
+                day: day.parse().map_err(|_| malformed())?,
+                part: part.parse().ok().filter(|part| *part == 1 || *part == 2).ok_or_else(malformed)?,
+                input: directory.join(input),
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Solves the expected answer's part of its input, and compares the result
+pub fn verify(expected: &ExpectedAnswer) -> Outcome {
+    let Some(day) = runner::find_day(expected.day) else { return Outcome::NoSolution };
+
+    let Ok(input) = Input::from_file(expected.input.to_string_lossy().into_owned()) else { return Outcome::NoInput };
+
+    let part = if expected.part == 1 { Part::One } else { Part::Two };
+
+    let answers = match std::panic::catch_unwind(|| (day.solve)(input, part)) {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(e)) => return Outcome::InvalidInput(e.to_string()),
+        Err(_) => return Outcome::Panicked,
+    };
+
+    let actual = if expected.part == 1 { answers.part1 } else { answers.part2 };
+
+    match actual {
+        Some(Ok(actual)) if actual == expected.answer => Outcome::Pass,
+        Some(Ok(actual)) => Outcome::Fail { actual },
+        Some(Err(e)) => Outcome::Unsolved(e.to_string()),
+        None => Outcome::NoSolution,
+    }
+}
+
+/// A line describing the outcome, followed by a diff of the answers if it failed
+pub fn report(expected: &ExpectedAnswer, outcome: &Outcome) -> String {
+    let name = format!("day {} part {} ({})", expected.day, expected.part, expected.input.display());
+
+    match outcome {
+        Outcome::Pass => format!("PASS {name}"),
+        Outcome::Fail { actual } => format!("FAIL {name}\n  - {}\n  + {actual}", expected.answer),
+        Outcome::NoSolution => format!("FAIL {name}: no solution for this day"),
+        Outcome::NoInput => format!("FAIL {name}: couldn't read the input"),
+        Outcome::InvalidInput(e) => format!("FAIL {name}: failed to parse the input: {e}"),
+        Outcome::Unsolved(e) => format!("FAIL {name}: failed to solve: {e}"),
+        Outcome::Panicked => format!("FAIL {name}: panicked"),
+    }
+}
+
+/// Checks every answer in the manifest, printing a report for each. Returns whether they all passed
+pub fn verify_all(manifest: &[ExpectedAnswer]) -> bool {
+    let mut failures = 0usize;
+
+    for expected in manifest {
+        let outcome = verify(expected);
+        if !matches!(outcome, Outcome::Pass) { failures += 1 }
+        println!("{}", report(expected, &outcome));
+    }
+
+    println!("{} passed, {failures} failed", manifest.len() - failures);
+    failures == 0
+}
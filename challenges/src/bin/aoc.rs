@@ -0,0 +1,53 @@
+use aoc_2025_challenges::{answers, runner};
+use aoc_2025_common::{Arg, Arguments, Part, get_input};
+
+const DAY: Arg<u8> = Arg::new("day", "the day to solve");
+const INPUTS: Arg<String> = Arg::new("inputs", "where `all` reads each day's input, with {day} replaced by its number")
+    .default("input/day{day}.txt");
+const ANSWERS: Arg<String> = Arg::new("answers", "the manifest of answers that `verify` checks").default("input/answers.txt");
+
+const USAGE: &str = "aoc day=N [part=1|2|both] [input path]
+       aoc all [part=1|2|both] [inputs=input/day{day}.txt]
+       aoc verify [answers=input/answers.txt]";
+
+fn main() {
+    let mut args = runner::common_args();
+    args.extend([DAY.spec(), INPUTS.spec(), ANSWERS.spec()]);
+    args.extend(runner::DAYS.iter().flat_map(|day| day.args));
+    Arguments::init(USAGE, &args);
+
+    let part = Part::get();
+
+    match Arguments::get().unnamed().first().map(String::as_str) {
+        Some("all") => {
+            runner::run_all(&INPUTS.value(), part);
+            return;
+        },
+        Some("verify") => {
+            let manifest = answers::load_manifest(ANSWERS.value().as_ref()).unwrap_or_else(|e| {
+                eprintln!("Failed to load the answers: {e}");
+                std::process::exit(1);
+            });
+            if !answers::verify_all(&manifest) { std::process::exit(1) }
+            return;
+        },
+        _ => {},
+    }
+
+    let day = DAY.value();
+    let day = runner::find_day(day).unwrap_or_else(|| {
+        let days: Box<[_]> = runner::DAYS.iter().map(|day| day.day.to_string()).collect();
+        eprintln!("Day {day} has no solution, expected one of {}", days.join(", "));
+        std::process::exit(2);
+    });
+
+    let input = get_input().unwrap_or_else(|e| {
+        eprintln!("Failed to read the input: {e}");
+        std::process::exit(1);
+    });
+
+    match (day.solve)(input, part) {
+        Ok(answers) => if !runner::print_answers(answers) { std::process::exit(1) },
+        Err(e) => { eprintln!("Failed to parse the input: {e}"); std::process::exit(1) },
+    }
+}
@@ -0,0 +1,233 @@
+use std::{collections::{HashMap, HashSet}, error::Error, fmt::Display, sync::LazyLock};
+use aoc_2025_common::*;
+use aoc_2025_proc_macros::*;
+
+#[derive(Clone, Debug, FromRegexCaptures)]
+#[from_regex(r"\[(?<lights>[\.\#]*)\] (?<buttons>(\(([0-9]+,?)+\) ?)+) \{(?<joltage_requirements>([0-9]+,?)+)\}")]
+pub struct Machine {
+    #[capture(name = "lights", with = parse_lights)]
+    lights_bitset: u16,
+    #[capture(name = "lights", with = count_lights)]
+    light_count: usize,
+    #[capture(with = parse_buttons)]
+    buttons: Box<[u16]>,
+    #[capture(with = parse_numbers)]
+    joltage_requirements: Box<[usize]>,
+}
+
+fn parse_lights(lights_str: &str) -> Result<u16, &'static str> {
+    if lights_str.chars().count() > u16::BITS as usize { return Err("too many lights to fit in a u16") }
+
+    Ok(lights_str.char_indices()
+        .map(|(index, c)| ((c == '#') as u16) << index)
+        .fold(0u16, |acc, b| acc | b))
+}
+
+fn count_lights(lights_str: &str) -> Result<usize, std::convert::Infallible> {
+    Ok(lights_str.chars().count())
+}
+
+static BRACKETED_REGEX: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new(r"\([^\)]*\)").unwrap());
+static NUMBER_REGEX: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new(r"[0-9]+").unwrap());
+
+fn parse_buttons(buttons_str: &str) -> Result<Box<[u16]>, Box<dyn Error + Send + Sync>> {
+    BRACKETED_REGEX
+        .find_iter(buttons_str)
+        .map(|s| {
+            let mut bits = 0u16;
+            for index in parse_numbers::<u32>(s.as_str())? {
+                bits |= 1u16.checked_shl(index).ok_or("a button refers to a light past the 16th")?;
+            }
+            Ok(bits)
+        })
+        .collect()
+}
+
+fn parse_numbers<T: std::str::FromStr>(str: &str) -> Result<Box<[T]>, T::Err> {
+    NUMBER_REGEX
+        .find_iter(str)
+        .map(|s| s.as_str().parse())
+        .collect()
+}
+
+/// Part 2 tries every combination of buttons, so there can't be too many of them
+const MAX_BUTTONS: usize = 16;
+
+impl Machine {
+    /// Checks that there's a joltage requirement for each light, that the buttons only refer to those lights, and
+    /// that there are few enough buttons to solve
+    pub fn validate(&self) -> Result<(), FromRegexCapturesError> {
+        if self.light_count != self.joltage_requirements.len() {
+            return Err(FromRegexCapturesError::Malformed("Number of lights does not equal number of joltage requirements"));
+        }
+
+        let counters = u32::try_from(self.joltage_requirements.len()).ok()
+            .and_then(|count| 1u64.checked_shl(count))
+            .map(|bit| bit - 1)
+            .ok_or(FromRegexCapturesError::Malformed("Too many joltage requirements"))?;
+
+        if self.buttons.len() > MAX_BUTTONS {
+            return Err(FromRegexCapturesError::Malformed("Too many buttons to try every combination of them"));
+        }
+
+        let consistent = self.buttons.iter()
+            .chain(std::iter::once(&self.lights_bitset))
+            .all(|&bits| u64::from(bits) & !counters == 0);
+
+        if !consistent {
+            return Err(FromRegexCapturesError::Malformed("Machine refers to lights without joltage requirements"));
+        }
+
+        Ok(())
+    }
+
+    pub fn a_star(&self, bitset: u16) -> Option<Vec<u16>> {
+        #[cfg(feature = "verbose")]
+        eprintln!("Trying to solve a_star({bitset}({bitset:#b}), {:?})", self.buttons);
+
+        let mut visited_nodes = HashSet::<u16>::new();
+        let mut next_nodes = Vec::<(u16, Vec<u16>)>::new();
+        next_nodes.push((bitset, Vec::new()));
+
+        loop {
+            let (node, path) = next_nodes.pop()?;
+
+            // check if we've reached the solution
+            if node == 0 {
+                #[cfg(feature = "verbose")]
+                eprintln!("Found a path - {path:?} - after considering {} nodes", visited_nodes.len());
+                return Some(path)
+            }
+
+            // try pressing each button
+            for &button in &self.buttons {
+                // find where we'd be if we pressed this button
+                let next_node = node ^ button;
+
+                // if we're going back to a state we've already visited, ignore this button
+                if visited_nodes.contains(&next_node) {
+                    continue
+                }
+                
+                // if we've already got a path to this node
+                if let Some((index, (_, current_distance))) = next_nodes.iter_mut().enumerate().find(|(_, (n, _))| *n == next_node) {
+                    // if the path is shorter, ignore the new one
+                    if current_distance.len() <= path.len() + 1 {
+                        continue
+                    }
+
+                    // otherwise, discard the old one
+                    next_nodes.remove(index);
+                }
+
+                let mut new_path = path.clone();
+                new_path.push(button);
+
+                // put lower weights at the end so they are popped sooner
+                let index = next_nodes.partition_point(|(n, p)| (p.len() > new_path.len()) && (n.count_zeros() > next_node.count_zeros()));
+                
+                next_nodes.insert(index, (next_node, new_path));
+            }
+            
+            visited_nodes.insert(node);
+        }
+    }
+    
+    pub fn bifurcate(&self) -> Option<usize> {
+        // every way of pressing each button at most once, with the lights it toggles
+        let presses: Box<[(u16, Box<[u16]>)]> = (0..1u32 << self.buttons.len())
+            .map(|subset| {
+                let buttons: Box<[u16]> = self.buttons.iter()
+                    .enumerate()
+                    .filter(|&(index, _)| subset & (1 << index) != 0)
+                    .map(|(_, &button)| button)
+                    .collect();
+
+                (buttons.iter().fold(0u16, |acc, b| acc ^ b), buttons)
+            })
+            .collect();
+
+        self.bifurcate_from(&self.joltage_requirements, &presses, &mut HashMap::new())
+    }
+
+    // pressing the same button twice doesn't change which counts are odd, so the odd counts have to be
+    // brought down by pressing some set of buttons once each. what's left is even, so it can be halved and
+    // solved the same way with every press counting double. the cheapest set of buttons isn't necessarily
+    // the best, so try all of them
+    fn bifurcate_from(&self, joltages: &[usize], presses: &[(u16, Box<[u16]>)], cache: &mut HashMap<Box<[usize]>, Option<usize>>) -> Option<usize> {
+        if joltages.iter().all(|&n| n == 0) { return Some(0) }
+        if let Some(&count) = cache.get(joltages) { return count }
+
+        #[cfg(feature = "verbose")]
+        eprintln!("Target is {joltages:?}");
+
+        // work out which counts we need to bring down
+        let bits = joltages.iter()
+            .enumerate()
+            .filter(|&(_, n)| n % 2 != 0)
+            .map(|(i, _)| 1u16 << i)
+            .sum();
+
+        let mut best = None;
+
+        for (_, buttons) in presses.iter().filter(|(lights, _)| *lights == bits) {
+            // move on to the next bits
+            let next_joltages: Option<Box<[usize]>> = joltages.iter()
+                .enumerate()
+                .map(|(index, &joltage)| {
+                    let decrement = buttons.iter()
+                        .filter(|&button| (1 << index) & button != 0)
+                        .count();
+
+                    Some(joltage.checked_sub(decrement)? >> 1)
+                })
+                .collect();
+
+            let Some(next_joltages) = next_joltages else { continue };
+
+            if let Some(count) = self.bifurcate_from(&next_joltages, presses, cache) {
+                let count = buttons.len() + 2 * count;
+                best = Some(best.map_or(count, |best: usize| best.min(count)));
+            }
+        }
+
+        cache.insert(joltages.into(), best);
+        best
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Box<[Machine]>;
+
+    fn parse(mut input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let machines: Box<[Machine]> = input.try_iter_by_regex(Machine::regex()).collect::<Result<_, _>>()?;
+
+        for machine in &machines {
+            #[cfg(feature = "verbose")]
+            eprintln!("Parsed Machine: {machine:?}");
+
+            machine.validate().map_err(|e| format!("{e}: {machine:?}"))?;
+        }
+
+        Ok(machines)
+    }
+
+    fn part1(machines: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(machines.iter()
+            .map(|machine| machine.a_star(machine.lights_bitset)
+                .map(|presses| presses.len())
+                .ok_or_else(|| format!("no presses turn on the lights of {machine:?}")))
+            .sum::<Result<usize, _>>()?)
+    }
+
+    fn part2(machines: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(machines.iter()
+            .map(|machine| machine.bifurcate()
+                .ok_or_else(|| format!("no presses meet the joltage requirements of {machine:?}")))
+            .sum::<Result<usize, _>>()?)
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_2025_common::*;
+use super::id_range::IDRange;
+use std::{error::Error, fmt::Display};
+
+#[derive(Clone, Copy, Debug)]
+pub struct ID(u64);
+
+impl ID {
+    pub fn is_valid_part1(&self) -> bool {
+        let str = self.0.to_string();
+        str[..str.len() / 2] != str[str.len() / 2..]
+    }
+
+    pub fn is_valid_part2(&self) -> bool {
+        let str = self.0.to_string();
+
+        // unfortunately, regex::Regex doesn't support backreferences, so we can't do this oneliner
+        // regex::Regex::new(r"^[0-9]\1+$").unwrap().find(&str).is_none()
+
+        for length in 1..=str.len() / 2 {
+            if str.len().is_multiple_of(length) {
+                let iter_1 = str.as_bytes().chunks(length);
+                let iter_2 = iter_1.clone().skip(1);
+                let mut iter = iter_1.zip(iter_2);
+
+                if iter.all(|(pattern_1, pattern_2)| pattern_1 == pattern_2) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+fn sum_invalid_ids(ids: &IntervalSet<u64>, is_valid: fn(&ID) -> bool) -> u64 {
+    ids.iter()
+        .flatten()
+        .filter(|num| !is_valid(&ID(*num)))
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Parsed = IntervalSet<u64>;
+
+    fn parse(mut input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let ranges: Box<[IDRange]> = input.try_iter_by_regex_strict(IDRange::regex(), |c| c == ',' || c.is_whitespace())
+            .collect::<Result<_, _>>()?;
+
+        Ok(ranges.iter().map(IDRange::range).collect())
+    }
+
+    fn part1(ids: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(sum_invalid_ids(ids, ID::is_valid_part1))
+    }
+
+    fn part2(ids: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(sum_invalid_ids(ids, ID::is_valid_part2))
+    }
+}
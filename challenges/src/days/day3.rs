@@ -0,0 +1,105 @@
+use aoc_2025_common::{Input, Solution};
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// How many batteries each part turns on in every bank
+const PART1_COUNT: usize = 2;
+const PART2_COUNT: usize = 12;
+
+#[derive(Debug)]
+pub struct BatteryBank {
+    batteries: Box<[u8]>
+}
+
+#[derive(Debug)]
+pub enum BatteryBankError {
+    Empty,
+    NotADigit(char),
+    TooShort { len: usize },
+}
+
+impl Display for BatteryBankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "expected a bank of batteries, found an empty line"),
+            Self::NotADigit(c) => write!(f, "expected a battery's joltage from 0 to 9, found {c:?}"),
+            Self::TooShort { len } => write!(f, "expected at least {PART2_COUNT} batteries in a bank, found {len}"),
+        }
+    }
+}
+
+impl Error for BatteryBankError {}
+
+impl FromStr for BatteryBank {
+    type Err = BatteryBankError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() { return Err(BatteryBankError::Empty) }
+
+        let batteries: Box<[_]> = s.chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as u8).ok_or(BatteryBankError::NotADigit(c)))
+            .collect::<Result<_, _>>()?;
+
+        // part 2 turns on that many batteries in every bank, so a shorter bank can't be solved
+        if batteries.len() < PART2_COUNT { return Err(BatteryBankError::TooShort { len: batteries.len() }) }
+
+        Ok(Self{ batteries })
+    }
+}
+
+impl std::fmt::Display for BatteryBank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for battery in &self.batteries {
+            write!(f, "{battery}")?
+        }
+
+        write!(f, "")
+    }
+}
+
+impl BatteryBank {
+    /// Panics if the bank has fewer than `count` batteries
+    pub fn largest_sequential_combination(&self, count: usize) -> usize {
+        let mut slice: &[u8] = &self.batteries;
+        let mut acc = 0usize;
+
+        for digit_index in 1..=count {
+            let remaining_digits = count - digit_index;
+
+            let (next_index, next_largest_number) = slice[..slice.len() - remaining_digits]
+                .iter().enumerate().rev() // rev to get the _first_ max, not the last
+                .max_by_key(|&(_index, &value)| value)
+                .unwrap();
+    
+            slice = &slice[next_index + 1..];
+            acc = 10 * acc + *next_largest_number as usize
+        }
+
+        acc
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Parsed = Box<[BatteryBank]>;
+
+    fn parse(mut input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let lines_before = input.first_line() + input.lines_read();
+
+        input.lines()
+            .enumerate()
+            .map(|(index, line)| line.parse().map_err(|e| format!("line {}: {e}", lines_before + index + 1).into()))
+            .collect()
+    }
+
+    fn part1(banks: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(banks.iter().map(|bank| bank.largest_sequential_combination(PART1_COUNT)).sum::<usize>())
+    }
+
+    fn part2(banks: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(banks.iter().map(|bank| bank.largest_sequential_combination(PART2_COUNT)).sum::<usize>())
+    }
+}
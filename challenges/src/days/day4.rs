@@ -0,0 +1,111 @@
+use aoc_2025_common::{Automaton, Boundary, Grid, Input, Neighbourhood, Neighbours, Solution};
+use std::{error::Error, fmt::{Debug, Display}};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum GridCell {
+    Empty,
+    Roll { accesible: bool },
+}
+
+impl GridCell {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, GridCell::Empty)
+    }
+
+    pub fn is_accessible(&self) -> bool {
+        matches!(self, GridCell::Roll { accesible: true })
+    }
+}
+
+/// Marks the rolls with fewer than 4 rolls around them as accessible
+pub fn mark_accessible(cell: &GridCell, neighbours: Neighbours<'_, GridCell>) -> GridCell {
+    match cell {
+        GridCell::Empty => GridCell::Empty,
+        GridCell::Roll { .. } => GridCell::Roll {
+            accesible: neighbours.filter(|cell| !cell.is_empty()).count() < 4,
+        },
+    }
+}
+
+/// Removes the rolls that are accessible
+pub fn remove_accessible(cell: &GridCell, neighbours: Neighbours<'_, GridCell>) -> GridCell {
+    match mark_accessible(cell, neighbours) {
+        GridCell::Roll { accesible: true } => GridCell::Empty,
+        cell => cell,
+    }
+}
+
+pub fn count_accessible(grid: &Grid<GridCell>) -> usize {
+    grid.cells().filter(|cell| cell.is_accessible()).count()
+}
+
+pub fn count_rolls(grid: &Grid<GridCell>) -> usize {
+    grid.cells().filter(|cell| !cell.is_empty()).count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Parsed = Grid<GridCell>;
+
+    fn parse(input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        Ok(Grid::parse_with(&input.collect_to_string(), GridCell::from_char)?)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        let mut automaton = Automaton::new(grid.clone(), Neighbourhood::Moore, Boundary::Empty);
+
+        #[cfg(feature = "verbose")]
+        eprintln!("{}", automaton.grid());
+
+        automaton.step(mark_accessible);
+
+        #[cfg(feature = "verbose")]
+        eprintln!("{}", automaton.grid());
+
+        Ok(count_accessible(automaton.grid()))
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        let mut automaton = Automaton::new(grid.clone(), Neighbourhood::Moore, Boundary::Empty);
+
+        #[cfg_attr(not(feature = "verbose"), allow(unused))]
+        let generations = automaton.run_until_stable(remove_accessible);
+
+        #[cfg(feature = "verbose")]
+        eprintln!("{}Stable after {generations} generations", automaton.grid());
+
+        Ok(count_rolls(grid) - count_rolls(automaton.grid()))
+    }
+}
+
+// parsing/formatting crap:
+
+impl GridCell {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '.' => Some(GridCell::Empty),
+            'x' => Some(GridCell::Roll { accesible: true }),
+            '@' => Some(GridCell::Roll { accesible: false }),
+             _  => None,
+        }
+    }
+}
+
+impl Display for GridCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridCell::Empty => write!(f, "."),
+            GridCell::Roll { accesible: true } => write!(f, "x"),
+            GridCell::Roll { accesible: false } => write!(f, "@"),
+        }
+    }
+}
+
+impl Debug for GridCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
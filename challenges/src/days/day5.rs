@@ -0,0 +1,38 @@
+use aoc_2025_common::{FromRegex, Input, IntervalSet, Solution};
+use super::id_range::IDRange;
+use std::{error::Error, fmt::Display};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    /// the fresh IDs, and the IDs to check
+    type Parsed = (IntervalSet<u64>, Box<[u64]>);
+
+    fn parse(input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let mut sections = input.sections();
+
+        let fresh = IDRange::parse_section(&mut sections, 0)?
+            .iter()
+            .map(IDRange::range)
+            .collect();
+
+        let ids = sections.take(1)?
+            .lines()
+            .map(|line| line.trim().parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok((fresh, ids))
+    }
+
+    fn part1((fresh, ids): &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(ids.iter()
+            .filter(|&&id| fresh.contains(id))
+            .count())
+    }
+
+    fn part2((fresh, _ids): &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(fresh.total_len())
+    }
+}
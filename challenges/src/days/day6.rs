@@ -0,0 +1,85 @@
+use aoc_2025_common::{Grid, Input, Solution, text};
+use std::{error::Error, fmt::Display};
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '+' => Some(Operation::Add),
+            '*' => Some(Operation::Multiply),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, numbers: &[usize]) -> usize {
+        match self {
+            Operation::Add => numbers.iter().sum(),
+            Operation::Multiply => numbers.iter().product(),
+        }
+    }
+}
+
+/// One problem on the worksheet, read both ways
+pub struct Problem {
+    operation: Operation,
+    /// the numbers read left to right, one per row
+    rows: Box<[usize]>,
+    /// the numbers read top to bottom, one per column
+    columns: Box<[usize]>,
+}
+
+impl Problem {
+    /// Parses a block of columns, with the numbers above the operation in the last row
+    fn parse(block: &Grid<char>) -> Result<Self, Box<dyn Error>> {
+        let operation_row = block.height() - 1;
+
+        let operation = block.row(operation_row)
+            .iter()
+            .find(|char| !char.is_whitespace())
+            .and_then(|&char| Operation::from_char(char))
+            .ok_or("expected a + or * under each problem")?;
+
+        let numbers = block.sub_grid(0..operation_row, 0..block.width());
+
+        Ok(Problem {
+            operation,
+            rows: text::horizontal_numbers(&numbers)?.into(),
+            columns: text::vertical_numbers(&numbers)?.into(),
+        })
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = Box<[Problem]>;
+
+    fn parse(input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        // the problems are separated by columns of spaces
+        let worksheet = text::pad_lines(&input.collect_to_string());
+
+        text::column_blocks(&worksheet)
+            .iter()
+            .map(Problem::parse)
+            .collect()
+    }
+
+    fn part1(problems: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(problems.iter()
+            .map(|problem| problem.operation.apply(&problem.rows))
+            .sum::<usize>())
+    }
+
+    fn part2(problems: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(problems.iter()
+            .map(|problem| problem.operation.apply(&problem.columns))
+            .sum::<usize>())
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_2025_common::{Grid, Input, Solution};
+use std::{collections::BTreeMap, error::Error, fmt::Display};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Cell {
+    BeamStart,
+    Empty,
+    BeamSplitter,
+}
+
+impl Cell {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            'S' => Some(Cell::BeamStart),
+            '.' => Some(Cell::Empty),
+            '^' => Some(Cell::BeamSplitter),
+             _  => None,
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::BeamStart => write!(f, "S"),
+            Cell::Empty => write!(f, "."),
+            Cell::BeamSplitter => write!(f, "^"),
+        }
+    }
+}
+
+struct Simulation {
+    num_splits: usize,
+    beams: BTreeMap<usize, usize>,
+}
+
+fn simulate(grid: &Grid<Cell>) -> Simulation {
+    let start_index = grid.row(0)
+        .iter()
+        .position(|&cell| cell == Cell::BeamStart)
+        .unwrap();
+
+    let mut beams = BTreeMap::new();
+    beams.insert(start_index, 1);
+
+    let mut num_splits = 0;
+
+    for row in grid.rows().skip(1) {
+        let mut new_beams = BTreeMap::new();
+
+        for (beam_index, beam_count) in beams {
+            match row[beam_index] {
+                Cell::BeamSplitter => {
+                    *new_beams.entry(beam_index - 1).or_default() += beam_count;
+                    *new_beams.entry(beam_index + 1).or_default() += beam_count;
+                    num_splits += 1;
+                },
+                Cell::Empty => { *new_beams.entry(beam_index).or_default() += beam_count },
+                Cell::BeamStart => panic!("Unexpected beam start at index {beam_index}"),
+            }
+        }
+
+        #[cfg(feature = "verbose")]
+        {
+            for (index, cell) in row.iter().enumerate() {
+                match (cell, new_beams.get(&index)) {
+                    (Cell::Empty, Some(num_beams)) => eprint!("{num_beams}"),
+                    (cell, None) => eprint!("{cell}"),
+                    p => panic!("Unexpected: {p:?}"),
+                }
+            }
+            eprintln!();
+        }
+
+        beams = new_beams;
+    }
+
+    Simulation { num_splits, beams }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = Grid<Cell>;
+
+    fn parse(input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        Ok(Grid::parse_with(&input.collect_to_string(), Cell::from_char)?)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(simulate(grid).num_splits)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(simulate(grid).beams.values().sum::<usize>())
+    }
+}
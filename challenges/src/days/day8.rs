@@ -0,0 +1,69 @@
+use aoc_2025_common::*;
+use aoc_2025_proc_macros::*;
+use std::{error::Error, fmt::Display};
+
+#[derive(Clone, Debug, FromRegexCaptures)]
+#[from_regex("(?<x>[0-9]+),(?<y>[0-9]+),(?<z>[0-9]+)")]
+pub struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point {
+    fn coordinates(&self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+// the example only connects 10 pairs, which example8.params sets
+const COUNT: Arg<usize> = Arg::new("count", "how many of the closest pairs part 1 connects").default("1000");
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    const ARGS: &'static [ArgSpec] = &[COUNT.spec()];
+
+    /// the junction boxes, and how many pairs part 1 connects
+    type Parsed = (Box<[Point]>, usize);
+
+    fn parse(mut input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let count = COUNT.value_for(&input)?;
+        let points = input.try_iter_by_regex_strict(Point::regex(), char::is_whitespace).collect::<Result<_, _>>()?;
+
+        Ok((points, count))
+    }
+
+    fn part1(&(ref points, count): &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        let junction_boxes = KdTree::new(points.iter().map(Point::coordinates));
+        let mut circuits = DisjointSet::new(points.len());
+
+        // join up neighbours into circuits
+        for (lhs, rhs) in junction_boxes.closest_pairs().take(count) {
+            circuits.union(lhs, rhs);
+        }
+
+        // sort the circuit sizes, a junction box on its own is a circuit of one
+        let mut largest_circuit_sizes: Box<[_]> = circuits.component_sizes().collect();
+        largest_circuit_sizes.sort();
+
+        #[cfg(feature="verbose")]
+        eprintln!("Circuit Sizes: {largest_circuit_sizes:?}");
+
+        // find the product of the three largest
+        Ok(largest_circuit_sizes.iter().rev().take(3).product::<usize>())
+    }
+
+    fn part2((points, _count): &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        let junction_boxes = KdTree::new(points.iter().map(Point::coordinates));
+
+        // the pair that finally connects everything is the longest edge of the minimum spanning tree,
+        // which doesn't exist if there's nothing to connect
+        let (lhs, rhs) = junction_boxes.minimum_spanning_tree().last()
+            .ok_or("there are fewer than two junction boxes to connect")?;
+
+        Ok(points[lhs].x * points[rhs].x)
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_2025_common::*;
+use aoc_2025_proc_macros::*;
+use std::{cell::Cell, error::Error, fmt::Display};
+
+#[derive(Clone, Debug, PartialEq, FromRegexCaptures)]
+#[from_regex("(?<x>[0-9]+),(?<y>[0-9]+)")]
+pub struct TileCoords {
+    x: i64,
+    y: i64,
+}
+
+impl TileCoords {
+    fn pair(&self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+struct AABB {
+    min_x: i64,
+    min_y: i64,
+    max_x: i64,
+    max_y: i64,
+    area_cache: Cell<Option<i64>>,
+}
+
+impl AABB {
+    pub fn from_tiles(a: &TileCoords, b: &TileCoords) -> Self {
+        Self {
+            min_x: a.x.min(b.x),
+            min_y: a.y.min(b.y),
+            max_x: a.x.max(b.x),
+            max_y: a.y.max(b.y),
+            area_cache: Cell::new(None),
+        }
+    }
+
+    pub fn area(&self) -> i64 {
+        if self.area_cache.get().is_none() { self.area_cache.set(Some((self.max_x - self.min_x + 1) * (self.max_y - self.min_y + 1))) }
+        self.area_cache.get().unwrap()
+    }
+}
+
+fn sorted_aabbs(red_tile_coords: &[TileCoords]) -> Box<[AABB]> {
+    let mut aabbs: Box<[_]> = red_tile_coords.iter()
+        .enumerate()
+        .flat_map(|(index, lhs)| red_tile_coords.iter().skip(index).map(move |rhs| (lhs, rhs)))
+        .map(|(lhs, rhs)| AABB::from_tiles(lhs, rhs))
+        .collect();
+    
+    aabbs.sort_by_key(|aabb| aabb.area());
+    aabbs.reverse();
+    aabbs
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Parsed = Box<[TileCoords]>;
+
+    fn parse(mut input: Input) -> Result<Self::Parsed, Box<dyn Error>> {
+        let red_tile_coords: Box<[_]> = input.try_iter_by_regex_strict(TileCoords::regex(), char::is_whitespace).collect::<Result<_, _>>()?;
+        if red_tile_coords.is_empty() { return Err("expected at least one red tile".into()) }
+
+        Ok(red_tile_coords)
+    }
+
+    fn part1(red_tile_coords: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        Ok(sorted_aabbs(red_tile_coords)[0].area())
+    }
+
+    fn part2(red_tile_coords: &Self::Parsed) -> Result<impl Display, Box<dyn Error>> {
+        // only part 2 needs the tiles to make a polygon
+        let vertices: Box<[_]> = red_tile_coords.iter().map(TileCoords::pair).collect();
+        let polygon = RectilinearPolygon::new(&vertices)?;
+
+        Ok(sorted_aabbs(red_tile_coords).iter()
+            .find(|aabb| polygon.contains_rectangle((aabb.min_x, aabb.min_y), (aabb.max_x, aabb.max_y)))
+            .expect("each red tile on its own is a rectangle inside the polygon")
+            .area())
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_2025_proc_macros::FromRegexCaptures;
+use std::ops::RangeInclusive;
+
+/// A range of IDs as written in the input, which is empty if it ends before it starts
+#[derive(Copy, Clone, Debug, FromRegexCaptures)]
+#[from_regex("(?<start>[0-9]+)-(?<end>[0-9]+)")]
+pub struct IDRange {
+    start: u64,
+    end: u64,
+}
+
+impl IDRange {
+    pub fn range(&self) -> RangeInclusive<u64> { self.start..=self.end }
+}
@@ -0,0 +1,6 @@
+pub mod answers;
+pub mod days;
+pub mod runner;
+
+#[cfg(feature = "timing")]
+mod timing;
@@ -0,0 +1,167 @@
+use aoc_2025_common::{ArgSpec, Arguments, COMMON_ARGS, Input, Part, Solution, get_input};
+use std::error::Error;
+use crate::days::*;
+
+#[cfg(feature = "timing")]
+use crate::timing::timed;
+
+#[cfg(not(feature = "timing"))]
+fn timed<T>(_day: u8, _stage: &'static str, f: impl FnOnce() -> T) -> T { f() }
+
+#[cfg(feature = "timing")]
+const TIMING_ARGS: &[ArgSpec] = &[crate::timing::TIMING_FORMAT.spec()];
+
+#[cfg(not(feature = "timing"))]
+const TIMING_ARGS: &[ArgSpec] = &[];
+
+/// A part's answer, or why the solution couldn't give one
+pub type Answer = Result<String, Box<dyn Error>>;
+
+/// The answers a solution gave to the parts it was asked to solve
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// A type-erased solution, so every day can be looked up and run by its number
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(Input, Part) -> Result<Answers, Box<dyn Error>>,
+    /// the options the solution reads, besides the common ones
+    pub args: &'static [ArgSpec],
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self { day: S::DAY, solve: solve::<S>, args: S::ARGS }
+    }
+}
+
+pub const DAYS: [Day; 10] = [
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+];
+
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// The options every binary accepts, along with `timing_format` if the feature is on
+pub fn common_args() -> Vec<ArgSpec> {
+    COMMON_ARGS.iter().chain(TIMING_ARGS).copied().collect()
+}
+
+/// Parses the input once, then solves the selected parts from it.
+/// With the `timing` feature, each stage reports how long it took
+pub fn solve<S: Solution>(input: Input, part: Part) -> Result<Answers, Box<dyn Error>> {
+    let parsed = timed(S::DAY, "parse", || S::parse(input))?;
+
+    Ok(Answers {
+        part1: part.runs_part1().then(|| timed(S::DAY, "part1", || S::part1(&parsed).map(|answer| answer.to_string()))),
+        part2: part.runs_part2().then(|| timed(S::DAY, "part2", || S::part2(&parsed).map(|answer| answer.to_string()))),
+    })
+}
+
+/// Solves the selected parts of a day for the input given on the command line, printing each answer on its own line
+pub fn run<S: Solution>() {
+    let mut args = common_args();
+    args.extend_from_slice(S::ARGS);
+    Arguments::init(&format!("day{} [part=1|2|both] [input path]", S::DAY), &args);
+
+    let input = get_input().unwrap_or_else(|e| {
+        eprintln!("Failed to read the input: {e}");
+        std::process::exit(1);
+    });
+
+    match solve::<S>(input, Part::get()) {
+        Ok(answers) => if !print_answers(answers) { std::process::exit(1) },
+        Err(e) => { eprintln!("Failed to parse the input: {e}"); std::process::exit(1) },
+    }
+}
+
+/// Prints each answer on its own line, or why that part failed. Returns whether every part was solved
+pub fn print_answers(answers: Answers) -> bool {
+    let mut solved = true;
+
+    for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+        match answer {
+            None => {},
+            Some(Ok(answer)) => println!("{answer}"),
+            Some(Err(e)) => { eprintln!("Failed to solve part {part}: {e}"); solved = false },
+        }
+    }
+
+    solved
+}
+
+/// Runs every day in sequence, reading each input from `input_pattern` with `{day}` replaced by the day's number,
+/// then prints a summary table of the answers
+pub fn run_all(input_pattern: &str, part: Part) {
+    let mut rows = vec![["Day".to_string(), "Part 1".to_string(), "Part 2".to_string()]];
+
+    for day in &DAYS {
+        let path = input_pattern.replace("{day}", &day.day.to_string());
+
+        let (part1, part2) = match Input::from_file(path) {
+            Err(e) => {
+                eprintln!("Skipping day {}: {e}", day.day);
+                ("no input".to_string(), "no input".to_string())
+            },
+            Ok(input) => match std::panic::catch_unwind(|| (day.solve)(input, part)) {
+                Err(_) => ("panicked".to_string(), "panicked".to_string()),
+                Ok(Err(e)) => {
+                    eprintln!("Failed to parse the input for day {}: {e}", day.day);
+                    ("invalid input".to_string(), "invalid input".to_string())
+                },
+                Ok(Ok(answers)) => (
+                    table_cell(day.day, 1, answers.part1),
+                    table_cell(day.day, 2, answers.part2),
+                ),
+            },
+        };
+
+        rows.push([day.day.to_string(), part1, part2]);
+    }
+
+    print_table(&rows);
+}
+
+fn table_cell(day: u8, part: u8, answer: Option<Answer>) -> String {
+    match answer {
+        None => "-".to_string(),
+        Some(Ok(answer)) => answer,
+        Some(Err(e)) => {
+            eprintln!("Failed to solve day {day} part {part}: {e}");
+            "error".to_string()
+        },
+    }
+}
+
+fn print_table<const N: usize>(rows: &[[String; N]]) {
+    let widths: [usize; N] = std::array::from_fn(|col| rows.iter()
+        .map(|row| row[col].len())
+        .max()
+        .unwrap_or(0));
+
+    for (index, row) in rows.iter().enumerate() {
+        let cells: Box<[_]> = row.iter().zip(widths)
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .collect();
+
+        println!("{}", cells.join(" | "));
+
+        // underline the header
+        if index == 0 {
+            let lines: Box<[_]> = widths.iter().map(|&width| "-".repeat(width)).collect();
+            println!("{}", lines.join("-+-"));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_2025_common::Arg;
+use std::time::{Duration, Instant};
+
+/// How timings are reported, selected with `timing_format=human` or `timing_format=json`
+#[derive(Clone, Copy)]
+pub enum Format {
+    Human,
+    /// one JSON object per line, so timings can be collected and compared between commits
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err("expected human or json".to_string()),
+        }
+    }
+}
+
+pub const TIMING_FORMAT: Arg<Format> = Arg::new("timing_format", "how timings are reported: human or json").default("human");
+
+/// Runs `f`, reporting how long it took on stderr
+pub fn timed<T>(day: u8, stage: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    report(day, stage, start.elapsed());
+    result
+}
+
+fn report(day: u8, stage: &'static str, elapsed: Duration) {
+    match TIMING_FORMAT.value() {
+        Format::Human => eprintln!("day {day} {stage} took {elapsed:?}"),
+        Format::Json => eprintln!("{{\"day\":{day},\"stage\":\"{stage}\",\"nanos\":{}}}", elapsed.as_nanos()),
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_2025_challenges::days::day10::Machine;
+use aoc_2025_common::FromRegexCapturesError;
+
+#[test]
+fn buttons_past_the_sixteenth_light_are_errors() {
+    let error = "[.#] (1,20) {1,2}".parse::<Machine>().unwrap_err();
+    assert!(matches!(error, FromRegexCapturesError::FailedToParse { field: "buttons", ref text, .. } if text == "(1,20)"));
+}
@@ -0,0 +1,8 @@
+use aoc_2025_challenges::days::day6::Day6;
+use aoc_2025_common::{Input, Solution};
+
+#[test]
+fn problems_without_an_operator_are_errors() {
+    let error = Day6::parse(Input::new("12 3\n45 6\n".to_string())).err().unwrap();
+    assert_eq!(error.to_string(), "expected a + or * under each problem");
+}
@@ -0,0 +1,25 @@
+use aoc_2025_challenges::answers::{self, Outcome};
+use std::path::Path;
+
+fn verify_day(day: u8) {
+    let manifest = answers::load_manifest(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/answers.txt"))).unwrap();
+
+    let expected: Box<[_]> = manifest.iter().filter(|expected| expected.day == day).collect();
+    assert!(!expected.is_empty(), "no expected answers for day {day}");
+
+    for expected in expected {
+        let outcome = answers::verify(expected);
+        assert!(matches!(outcome, Outcome::Pass), "{}", answers::report(expected, &outcome));
+    }
+}
+
+#[test] fn day1() { verify_day(1) }
+#[test] fn day2() { verify_day(2) }
+#[test] fn day3() { verify_day(3) }
+#[test] fn day4() { verify_day(4) }
+#[test] fn day5() { verify_day(5) }
+#[test] fn day6() { verify_day(6) }
+#[test] fn day7() { verify_day(7) }
+#[test] fn day8() { verify_day(8) }
+#[test] fn day9() { verify_day(9) }
+#[test] fn day10() { verify_day(10) }
@@ -0,0 +1,102 @@
+use aoc_2025_common::{FromRegex, FromRegexCaptures, FromRegexCapturesError};
+use aoc_2025_proc_macros::FromRegexCaptures;
+
+#[derive(Debug, PartialEq, FromRegexCaptures)]
+#[from_regex("([a-z]+)=([0-9]+)")]
+struct Assignment(String, u32);
+
+#[derive(Debug, PartialEq, FromRegexCaptures)]
+#[from_regex("(?<label>[a-z]+)?:([0-9]+)")]
+struct Labelled(#[capture(name = "label")] Option<String>, u32);
+
+#[derive(Debug, PartialEq, FromRegexCaptures)]
+#[from_regex("(?<turn>(?<direction>[LR])(?<steps>[0-9]+))|(?<jump>J(?<to>[0-9]+))|(?<halt>H)")]
+enum Instruction {
+    #[regex_capture("turn")]
+    Turn { direction: char, steps: u32 },
+    #[regex_capture("jump")]
+    Jump(#[capture(name = "to")] usize),
+    #[regex_capture("halt")]
+    Halt,
+}
+
+#[derive(Debug, PartialEq, FromRegexCaptures)]
+struct Size {
+    width: u32,
+    height: u32,
+}
+
+fn parse_flags(flags: &str) -> Result<Vec<bool>, &'static str> {
+    flags.chars()
+        .map(|c| match c { '#' => Ok(true), '.' => Ok(false), _ => Err("expected # or .") })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, FromRegexCaptures)]
+#[from_regex(r"(?<name>[a-z]+) (?<width>[0-9]+)x(?<height>[0-9]+)(?: \[(?<flags>[^\]]*)\])?(?: \*(?<count>[0-9]+))?")]
+struct Item {
+    name: String,
+    #[capture(flatten)]
+    size: Size,
+    #[capture(default, with = parse_flags)]
+    flags: Vec<bool>,
+    count: Option<u32>,
+}
+
+#[test]
+fn tuple_structs_read_positional_groups() {
+    assert_eq!("abc=12".parse::<Assignment>().unwrap(), Assignment("abc".to_string(), 12));
+    assert_eq!("x:3".parse::<Labelled>().unwrap(), Labelled(Some("x".to_string()), 3));
+    assert_eq!(":3".parse::<Labelled>().unwrap(), Labelled(None, 3));
+}
+
+#[test]
+fn enums_pick_the_variant_that_matched() {
+    assert_eq!("L12".parse::<Instruction>().unwrap(), Instruction::Turn { direction: 'L', steps: 12 });
+    assert_eq!("J4".parse::<Instruction>().unwrap(), Instruction::Jump(4));
+    assert_eq!("H".parse::<Instruction>().unwrap(), Instruction::Halt);
+}
+
+#[test]
+fn enums_report_every_variant_group_when_none_matched() {
+    let regex = regex::Regex::new("(?<turn>x)?|(?<jump>y)|(?<halt>z)").unwrap();
+    let captures = regex.captures("").unwrap();
+
+    assert!(matches!(
+        Instruction::from_regex_captures(&captures),
+        Err(FromRegexCapturesError::MissingField("turn or jump or halt")),
+    ));
+}
+
+#[test]
+fn capture_options() {
+    assert_eq!("box 3x4 [#.#] *2".parse::<Item>().unwrap(), Item {
+        name: "box".to_string(),
+        size: Size { width: 3, height: 4 },
+        flags: vec![true, false, true],
+        count: Some(2),
+    });
+
+    // flags falls back to its default, and count to None
+    assert_eq!("bag 1x2".parse::<Item>().unwrap(), Item {
+        name: "bag".to_string(),
+        size: Size { width: 1, height: 2 },
+        flags: Vec::new(),
+        count: None,
+    });
+}
+
+#[test]
+fn with_functions_report_their_errors() {
+    let error = "box 3x4 [#?#]".parse::<Item>().unwrap_err();
+    assert!(matches!(error, FromRegexCapturesError::FailedToParse { field: "flags", ref text, .. } if text == "#?#"));
+}
+
+#[test]
+fn missing_groups_are_errors_without_options() {
+    let regex = regex::Regex::new("(?<width>[0-9]+)x(?<height>[0-9]+)?").unwrap();
+    let captures = regex.captures("3x").unwrap();
+
+    assert!(matches!(Size::from_regex_captures(&captures), Err(FromRegexCapturesError::MissingField("height"))));
+    assert!(Item::regex().is_match("box 1x1"));
+}
@@ -0,0 +1,207 @@
+use crate::Input;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::{LazyLock, OnceLock};
+
+/// The parsed command line. Arguments of the form `name=value` are named, and everything else is unnamed
+pub struct Arguments {
+    named: HashMap<String, String>,
+    unnamed: Vec<String>,
+}
+
+static ARGUMENTS: OnceLock<Arguments> = OnceLock::new();
+
+/// Named arguments look like `name=value`, where the name is made of letters, digits and underscores
+static NAMED_ARGUMENT_REGEX: LazyLock<regex::Regex> = LazyLock::new(||
+    regex::Regex::new("^(?<name>[A-Za-z_][A-Za-z0-9_]*)=(?<value>.*)$").unwrap());
+
+impl Arguments {
+    /// Parses the command line, checking every named argument against the options the binary declares.
+    /// Prints the usage and options for `--help`, and exits with an error for unknown names or invalid values.
+    /// Must be called before anything reads the arguments
+    pub fn init(usage: &str, options: &[ArgSpec]) {
+        let args: Box<[String]> = std::env::args().skip(1).collect();
+
+        if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+            print!("{}", help(usage, options));
+            std::process::exit(0);
+        }
+
+        let arguments = match Self::parse(args, Some(options)) {
+            Ok(arguments) => arguments,
+            Err(e) => {
+                eprintln!("{e}\n\n{}", help(usage, options));
+                std::process::exit(2);
+            },
+        };
+
+        if ARGUMENTS.set(arguments).is_err() {
+            panic!("The arguments were read before Arguments::init was called");
+        }
+    }
+
+    /// The parsed command line. If `init` wasn't called, the arguments are parsed without being checked
+    pub fn get() -> &'static Self {
+        ARGUMENTS.get_or_init(|| Self::parse(std::env::args().skip(1), None).unwrap())
+    }
+
+    /// Splits the arguments into named and unnamed, and if `options` are given checks the named arguments against them
+    pub fn parse(args: impl IntoIterator<Item = String>, options: Option<&[ArgSpec]>) -> Result<Self, ArgumentsError> {
+        let mut result = Self { named: HashMap::new(), unnamed: Vec::new() };
+
+        for arg in args {
+            let Some(named_arg) = NAMED_ARGUMENT_REGEX.captures(&arg) else {
+                result.unnamed.push(arg);
+                continue;
+            };
+
+            let name = &named_arg["name"];
+            let value = &named_arg["value"];
+
+            if let Some(options) = options {
+                let option = options.iter()
+                    .find(|option| option.name == name)
+                    .ok_or_else(|| ArgumentsError::Unknown(name.to_string()))?;
+
+                (option.validate)(value).map_err(|error| ArgumentsError::InvalidValue {
+                    name: option.name,
+                    value: value.to_string(),
+                    error,
+                })?;
+            }
+
+            result.named.insert(name.to_string(), value.to_string());
+        }
+
+        Ok(result)
+    }
+
+    /// The value of a named argument as it was given on the command line
+    pub fn named(&self, name: &str) -> Option<&str> {
+        self.named.get(name).map(String::as_str)
+    }
+
+    pub fn unnamed(&self) -> &[String] {
+        &self.unnamed
+    }
+}
+
+/// A named option that a binary accepts, as `name=value`
+pub struct Arg<T> {
+    name: &'static str,
+    help: &'static str,
+    default: Option<&'static str>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr> Arg<T>
+    where T::Err: Display
+{
+    pub const fn new(name: &'static str, help: &'static str) -> Self {
+        Self { name, help, default: None, _type: PhantomData }
+    }
+
+    /// The value to use when the option isn't given. It's parsed in the same way as the command line
+    pub const fn default(self, default: &'static str) -> Self {
+        Self { default: Some(default), ..self }
+    }
+
+    pub const fn name(&self) -> &'static str { self.name }
+
+    /// The type erased description of the option, for `Arguments::init`
+    pub const fn spec(&self) -> ArgSpec {
+        ArgSpec { name: self.name, help: self.help, default: self.default, validate: validate::<T> }
+    }
+
+    /// The value given on the command line, or the default if it wasn't given
+    pub fn get(&self) -> Option<T> {
+        let value = Arguments::get().named(self.name).or(self.default)?;
+
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(e) => panic!("Invalid value for {}: {e}", self.name),
+        }
+    }
+
+    /// The value given on the command line, or the default if it wasn't given. Exits with an error if neither
+    pub fn value(&self) -> T {
+        self.get().unwrap_or_else(|| {
+            eprintln!("Expected {}=..., {}", self.name, self.help);
+            std::process::exit(2);
+        })
+    }
+
+    /// For puzzle parameters that differ between inputs. The value given on the command line, otherwise the one
+    /// from the input's `.params` file, otherwise the default
+    pub fn value_for(&self, input: &Input) -> Result<T, ArgumentsError> {
+        self.value_in(Arguments::get(), input)
+    }
+
+    /// Like `value_for`, but reading the command line from `arguments`
+    pub fn value_in(&self, arguments: &Arguments, input: &Input) -> Result<T, ArgumentsError> {
+        let value = arguments.named(self.name)
+            .or_else(|| input.param(self.name))
+            .or(self.default)
+            .ok_or(ArgumentsError::Missing { name: self.name, help: self.help })?;
+
+        value.parse().map_err(|e: T::Err| ArgumentsError::InvalidValue {
+            name: self.name,
+            value: value.to_string(),
+            error: e.to_string(),
+        })
+    }
+}
+
+fn validate<T: FromStr>(value: &str) -> Result<(), String>
+    where T::Err: Display
+{
+    value.parse::<T>().map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// A named option that a binary accepts, without its type
+#[derive(Clone, Copy)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub default: Option<&'static str>,
+    /// checks that a value parses as the option's type
+    pub validate: fn(&str) -> Result<(), String>,
+}
+
+/// The usage, followed by a line for each option
+pub fn help(usage: &str, options: &[ArgSpec]) -> String {
+    let names: Box<[String]> = options.iter().map(|option| format!("{}=", option.name)).collect();
+    let width = names.iter().map(String::len).chain(["--help".len()]).max().unwrap_or(0);
+
+    let mut help = format!("usage: {usage}\n\noptions:\n");
+
+    for (name, option) in names.iter().zip(options) {
+        let default = option.default.map(|default| format!(" (default: {default})")).unwrap_or_default();
+        help += &format!("  {name:<width$}  {}{default}\n", option.help);
+    }
+
+    help += &format!("  {:<width$}  print this message\n", "--help");
+    help
+}
+
+#[derive(Debug)]
+pub enum ArgumentsError {
+    Unknown(String),
+    InvalidValue { name: &'static str, value: String, error: String },
+    Missing { name: &'static str, help: &'static str },
+}
+
+impl Display for ArgumentsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "unknown option `{name}`"),
+            Self::InvalidValue { name, value, error } => write!(f, "invalid value {value:?} for `{name}`: {error}"),
+            Self::Missing { name, help } => write!(f, "expected {name}=..., {help}"),
+        }
+    }
+}
+
+impl Error for ArgumentsError {}
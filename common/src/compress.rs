@@ -0,0 +1,109 @@
+use crate::Grid;
+use std::ops::Range;
+
+/// Maps sparse coordinates to consecutive indices. Each value starts a block of real coordinates that runs up to the
+/// next value, and the last value is a block of one, so everything from the first value to one after the last maps
+/// to some index
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoordinateCompressor {
+    /// where each block starts, in order
+    starts: Vec<i64>,
+}
+
+impl CoordinateCompressor {
+    /// Compresses exactly these values, so the coordinates between two values share a block with the first
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut starts: Vec<_> = values.into_iter().collect();
+        starts.sort();
+        starts.dedup();
+
+        Self { starts }
+    }
+
+    /// Compresses these values with the coordinates either side of them, so every value is a block of one and the
+    /// gaps between values, and around the outside, have blocks of their own
+    pub fn with_neighbours(values: impl IntoIterator<Item = i64>) -> Self {
+        Self::new(values.into_iter().flat_map(|value| [value - 1, value, value + 1]))
+    }
+
+    pub fn len(&self) -> usize { self.starts.len() }
+    pub fn is_empty(&self) -> bool { self.starts.is_empty() }
+
+    /// The index of a value that was compressed
+    pub fn compress(&self, value: i64) -> Option<usize> {
+        self.starts.binary_search(&value).ok()
+    }
+
+    /// The index of the block that `value` falls in, if it's in any
+    pub fn block(&self, value: i64) -> Option<usize> {
+        let index = self.starts.partition_point(|&start| start <= value).checked_sub(1)?;
+        self.range(index).contains(&value).then_some(index)
+    }
+
+    /// The value that was compressed to `index`, which is where its block starts
+    pub fn decompress(&self, index: usize) -> i64 {
+        self.starts[index]
+    }
+
+    /// The real coordinates in the block at `index`
+    pub fn range(&self, index: usize) -> Range<i64> {
+        let end = self.starts.get(index + 1).copied().unwrap_or(self.starts[index] + 1);
+        self.starts[index]..end
+    }
+
+    /// How many real coordinates are in the block at `index`
+    pub fn width(&self, index: usize) -> u64 {
+        let range = self.range(index);
+        range.end.abs_diff(range.start)
+    }
+
+    /// The blocks covering `min..=max`, if they're all within the blocks
+    pub fn blocks(&self, min: i64, max: i64) -> Option<Range<usize>> {
+        Some(self.block(min)?..self.block(max)? + 1)
+    }
+}
+
+/// A grid over compressed `x` and `y` coordinates, where each cell stands for a rectangle of real coordinates.
+/// Positions are `(row, col)` like `Grid`, with rows along `y` and columns along `x`
+#[derive(Clone, Debug)]
+pub struct CompressedGrid<T> {
+    pub xs: CoordinateCompressor,
+    pub ys: CoordinateCompressor,
+    pub cells: Grid<T>,
+}
+
+impl<T> CompressedGrid<T> {
+    /// Creates each cell from the real `(x, y)` where its rectangle starts
+    pub fn from_fn(xs: CoordinateCompressor, ys: CoordinateCompressor, mut f: impl FnMut((i64, i64)) -> T) -> Self {
+        let cells = Grid::from_fn(xs.len(), ys.len(), |(row, col)| f((xs.decompress(col), ys.decompress(row))));
+        Self { xs, ys, cells }
+    }
+
+    /// The position of the cell containing the real `(x, y)`
+    pub fn position(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        Some((self.ys.block(y)?, self.xs.block(x)?))
+    }
+
+    /// The cell containing the real `(x, y)`
+    pub fn get(&self, point: (i64, i64)) -> Option<&T> {
+        self.cells.get(self.position(point)?)
+    }
+
+    /// How many real coordinates the cell at `(row, col)` covers
+    pub fn area(&self, (row, col): (usize, usize)) -> u64 {
+        self.ys.width(row) * self.xs.width(col)
+    }
+
+    /// The area each cell covers
+    pub fn areas(&self) -> Grid<u64> {
+        self.cells.map(|position, _| self.area(position))
+    }
+
+    /// The total real area of the cells that match `predicate`
+    pub fn area_where(&self, mut predicate: impl FnMut(&T) -> bool) -> u64 {
+        self.cells.positions()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(position, _)| self.area(position))
+            .sum()
+    }
+}
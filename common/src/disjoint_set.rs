@@ -0,0 +1,84 @@
+/// Tracks which of `0..len` are connected to each other, as a forest of sets that can be merged.
+/// Finding a set compresses the path to its root, and merging puts the smaller set under the larger, so both are
+/// close to constant time
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    /// the size of each set, only kept up to date for roots
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` sets, each containing one element
+    pub fn new(len: usize) -> Self {
+        Self { parent: (0..len).collect(), size: vec![1; len], components: len }
+    }
+
+    pub fn len(&self) -> usize { self.parent.len() }
+    pub fn is_empty(&self) -> bool { self.parent.is_empty() }
+
+    /// How many separate sets there are
+    pub fn components(&self) -> usize { self.components }
+
+    /// The root of the set containing `element`, which is the same for every element in the set
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root { root = self.parent[root] }
+
+        // point everything on the way straight at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns whether they were separate sets
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b { return false }
+
+        let (larger, smaller) = if self.size[a] >= self.size[b] { (a, b) } else { (b, a) };
+        self.parent[smaller] = larger;
+        self.size[larger] += self.size[smaller];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements are in the set containing `element`
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// The size of every set
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+    }
+
+    /// The elements of every set, in increasing order, with the sets ordered by their smallest element
+    pub fn sets(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        // which of the sets each root's elements go in, assigned as the roots are first seen
+        let mut set_indices = vec![None; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *set_indices[root].get_or_insert_with(|| {
+                sets.push(Vec::with_capacity(self.size[root]));
+                sets.len() - 1
+            });
+            sets[index].push(element);
+        }
+
+        sets.into_iter()
+    }
+}
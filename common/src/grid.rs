@@ -0,0 +1,355 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, indexed by `(row, col)` from the top left
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    cells: Box<[T]>,
+    width: usize,
+    height: usize,
+}
+
+/// The offsets of the 4 orthogonal neighbours, as `(row, col)`
+const NEIGHBOUR_OFFSETS_4: [(isize, isize); 4] = [
+              (-1,  0),
+    ( 0, -1),           ( 0,  1),
+              ( 1,  0),
+];
+
+/// The offsets of all 8 neighbours, including diagonals, as `(row, col)`
+const NEIGHBOUR_OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1), (-1,  0), (-1,  1),
+    ( 0, -1),           ( 0,  1),
+    ( 1, -1), ( 1,  0), ( 1,  1),
+];
+
+impl<T> Grid<T> {
+    /// Creates a grid by calling `f` with the position of each cell
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+
+        Self { cells, width, height }
+    }
+
+    /// Parses a grid with one line per row and one character per cell, which `parse_cell` turns into a cell.
+    /// Returns an error if `parse_cell` returns `None`, or the rows aren't all the same width
+    pub fn parse_with(s: &str, mut parse_cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridFromStrError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in s.lines().enumerate() {
+            let row_start = cells.len();
+
+            for (col, char) in line.chars().enumerate() {
+                cells.push(parse_cell(char).ok_or(GridFromStrError::UnexpectedCharacter { row, col, char })?);
+            }
+
+            let actual = cells.len() - row_start;
+            match width {
+                Some(expected) if actual != expected => return Err(GridFromStrError::InconsistentWidth { row, expected, actual }),
+                Some(_) => {},
+                None => width = Some(actual),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self { cells: cells.into(), width: width.ok_or(GridFromStrError::Empty)?, height })
+    }
+
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if !self.contains(position) { return None }
+        let index = self.index_of(position);
+        Some(&mut self.cells[index])
+    }
+
+    /// Gets the cell at the position, wrapping around the edges in both directions
+    pub fn get_wrapping(&self, (row, col): (isize, isize)) -> &T {
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width as isize) as usize;
+        &self[(row, col)]
+    }
+
+    /// Gets the cell at `position` moved by `(row, col)`, if that's inside the grid
+    pub fn get_offset(&self, position: (usize, usize), offset: (isize, isize)) -> Option<&T> {
+        self.get(self.offset(position, offset)?)
+    }
+
+    fn offset(&self, (row, col): (usize, usize), (row_offset, col_offset): (isize, isize)) -> Option<(usize, usize)> {
+        let position = (row.checked_add_signed(row_offset)?, col.checked_add_signed(col_offset)?);
+        self.contains(position).then_some(position)
+    }
+
+    fn index_of(&self, (row, col): (usize, usize)) -> usize {
+        col + row * self.width
+    }
+
+    /// Every cell, row by row
+    pub fn cells(&self) -> impl Iterator<Item = &T> { self.cells.iter() }
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> { self.cells.iter_mut() }
+
+    /// Every cell along with its position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.iter()
+            .enumerate()
+            .map(|(index, cell)| ((index / self.width, index % self.width), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks panics on 0, but a grid with no columns has nothing to iterate anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {col} is out of range for a grid {} wide", self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// The orthogonal neighbours of the position that are inside the grid, along with their positions
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(position, &NEIGHBOUR_OFFSETS_4)
+    }
+
+    /// The orthogonal and diagonal neighbours of the position that are inside the grid, along with their positions
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(position, &NEIGHBOUR_OFFSETS_8)
+    }
+
+    fn neighbours<'a>(&'a self, position: (usize, usize), offsets: &'static [(isize, isize)]) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        offsets.iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+            .map(|position| (position, &self[position]))
+    }
+
+    /// Creates a grid of the same size, by calling `f` with each cell and its position
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.positions().map(|(position, cell)| f(position, cell)).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Swaps the rows and columns
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.height, self.width, |(row, col)| self[(col, row)].clone())
+    }
+
+    /// Copies out the cells in the given rows and columns
+    pub fn sub_grid(&self, rows: std::ops::Range<usize>, cols: std::ops::Range<usize>) -> Grid<T> where T: Clone {
+        Grid::from_fn(cols.len(), rows.len(), |(row, col)| self[(rows.start + row, cols.start + col)].clone())
+    }
+
+    /// Displays the grid one row per line, formatting each cell with `format_cell`
+    pub fn display_with<F>(&self, format_cell: F) -> GridDisplay<'_, T, F>
+        where F: Fn(&T, &mut Formatter<'_>) -> std::fmt::Result
+    {
+        GridDisplay { grid: self, format_cell }
+    }
+}
+
+/// Which cells around a cell count as its neighbours
+#[derive(Clone, Copy, Debug)]
+pub enum Neighbourhood {
+    /// the 4 orthogonal neighbours
+    VonNeumann,
+    /// all 8 neighbours, including diagonals
+    Moore,
+    /// any `(row, col)` offsets from the cell
+    Custom(&'static [(isize, isize)]),
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbourhood::VonNeumann => &NEIGHBOUR_OFFSETS_4,
+            Neighbourhood::Moore => &NEIGHBOUR_OFFSETS_8,
+            Neighbourhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// What the neighbours of cells on the edge of the grid are
+#[derive(Clone, Copy, Debug)]
+pub enum Boundary {
+    /// neighbours outside the grid are left out
+    Empty,
+    /// the grid wraps around, so the neighbours are on the opposite edge
+    Wrap,
+    /// the nearest cell inside the grid is used instead
+    Clamp,
+}
+
+/// The neighbours of a cell, as given by a `Neighbourhood` and `Boundary`
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    position: (usize, usize),
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+    boundary: Boundary,
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, col) = self.position;
+
+        for &(row_offset, col_offset) in &mut self.offsets {
+            let neighbour = match self.boundary {
+                Boundary::Empty => self.grid.get_offset(self.position, (row_offset, col_offset)),
+                Boundary::Wrap => Some(self.grid.get_wrapping((row as isize + row_offset, col as isize + col_offset))),
+                Boundary::Clamp => Some(&self.grid[(
+                    (row as isize + row_offset).clamp(0, self.grid.height as isize - 1) as usize,
+                    (col as isize + col_offset).clamp(0, self.grid.width as isize - 1) as usize,
+                )]),
+            };
+
+            if neighbour.is_some() { return neighbour }
+        }
+
+        None
+    }
+}
+
+impl<T> Grid<T> {
+    /// The neighbours of the position, for cellular automata
+    pub fn neighbours_in(&self, position: (usize, usize), neighbourhood: Neighbourhood, boundary: Boundary) -> Neighbours<'_, T> {
+        Neighbours { grid: self, position, offsets: neighbourhood.offsets().iter(), boundary }
+    }
+
+    /// Computes the next generation of every cell into `next` from this generation, so every cell sees its
+    /// neighbours as they were before the step. `next` is resized to match if it's a different size
+    pub fn step_into(&self, next: &mut Grid<T>, neighbourhood: Neighbourhood, boundary: Boundary, mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T)
+        where T: Clone
+    {
+        if next.width != self.width || next.height != self.height { *next = self.clone() }
+
+        for (index, (position, cell)) in self.positions().enumerate() {
+            next.cells[index] = rule(cell, self.neighbours_in(position, neighbourhood, boundary));
+        }
+    }
+}
+
+/// A grid that's updated one generation at a time, double buffered so the new generation never reads from itself
+pub struct Automaton<T> {
+    current: Grid<T>,
+    next: Grid<T>,
+    neighbourhood: Neighbourhood,
+    boundary: Boundary,
+}
+
+impl<T: Clone> Automaton<T> {
+    pub fn new(grid: Grid<T>, neighbourhood: Neighbourhood, boundary: Boundary) -> Self {
+        Self { next: grid.clone(), current: grid, neighbourhood, boundary }
+    }
+
+    pub fn grid(&self) -> &Grid<T> { &self.current }
+    pub fn into_grid(self) -> Grid<T> { self.current }
+
+    /// Replaces every cell with `rule` applied to it and its neighbours
+    pub fn step(&mut self, rule: impl FnMut(&T, Neighbours<'_, T>) -> T) {
+        self.current.step_into(&mut self.next, self.neighbourhood, self.boundary, rule);
+        std::mem::swap(&mut self.current, &mut self.next);
+    }
+
+    /// Steps until a step doesn't change any cells, and returns how many steps did
+    pub fn run_until_stable(&mut self, mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T) -> usize
+        where T: PartialEq
+    {
+        let mut generations = 0;
+
+        loop {
+            self.step(&mut rule);
+            if self.current == self.next { return generations }
+            generations += 1;
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is out of range for a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is out of range for a {width}x{height} grid"))
+    }
+}
+
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    format_cell: F,
+}
+
+impl<T, F> Display for GridDisplay<'_, T, F>
+    where F: Fn(&T, &mut Formatter<'_>) -> std::fmt::Result
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                (self.format_cell)(cell, f)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_with(T::fmt))
+    }
+}
+
+#[derive(Debug)]
+pub enum GridFromStrError {
+    UnexpectedCharacter { row: usize, col: usize, char: char },
+    InconsistentWidth { row: usize, expected: usize, actual: usize },
+    Empty,
+}
+
+impl Display for GridFromStrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter { row, col, char } =>
+                write!(f, "unexpected character {char:?} at line {}, column {}", row + 1, col + 1),
+            Self::InconsistentWidth { row, expected, actual } =>
+                write!(f, "line {} is {actual} wide, but the grid is {expected} wide", row + 1),
+            Self::Empty => write!(f, "the grid is empty"),
+        }
+    }
+}
+
+impl Error for GridFromStrError {}
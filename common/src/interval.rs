@@ -0,0 +1,156 @@
+use std::ops::RangeInclusive;
+
+/// Integers that an `IntervalSet` can hold, which have a value before and after them except at their limits
+pub trait Discrete: Copy + Ord {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    /// How many values are in `start..=end`, which mustn't be empty
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {$(
+        impl Discrete for $t {
+            fn successor(self) -> Option<Self> { self.checked_add(1) }
+            fn predecessor(self) -> Option<Self> { self.checked_sub(1) }
+            fn count(start: Self, end: Self) -> u128 { end.abs_diff(start) as u128 + 1 }
+        }
+    )*};
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers stored as inclusive ranges. The ranges are kept sorted, and merged whenever they overlap or
+/// touch, so each one is separated from the next by at least one value that isn't in the set
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// `(start, end)` of each range, both inclusive
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Sorts and merges ranges into the form the set keeps them in
+    fn normalise(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort();
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last_end)) if last_end.successor().is_none_or(|next| start <= next) => {
+                    *last_end = end.max(*last_end);
+                },
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    /// Adds every value in `range`, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end { return }
+
+        // the ranges from `first` up to `last` overlap or touch the new one
+        let first = self.ranges
+            .partition_point(|&(_, other_end)| other_end.successor().is_some_and(|next| next < start));
+        let last = self.ranges
+            .partition_point(|&(other_start, _)| other_start <= end || Some(other_start) == end.successor());
+
+        let merged = match &self.ranges[first..last] {
+            [] => (start, end),
+            [(first_start, _), .., (_, last_end)] | [(first_start, last_end)] =>
+                (start.min(*first_start), end.max(*last_end)),
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` is in the set, found by binary search
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(index).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// How many separate ranges there are
+    pub fn len(&self) -> usize { self.ranges.len() }
+    pub fn is_empty(&self) -> bool { self.ranges.is_empty() }
+
+    /// How many values are in the set
+    pub fn total_len(&self) -> u128 {
+        self.ranges.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    /// The ranges, in order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Every value that's in either set
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalise(self.ranges.iter().chain(&other.ranges).copied().collect())
+    }
+
+    /// Every value that's in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut lhs, mut rhs) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+
+        while let (Some(&&(lhs_start, lhs_end)), Some(&&(rhs_start, rhs_end))) = (lhs.peek(), rhs.peek()) {
+            let (start, end) = (lhs_start.max(rhs_start), lhs_end.min(rhs_end));
+            if start <= end { ranges.push((start, end)) }
+
+            // whichever ends first can't overlap anything else
+            if lhs_end < rhs_end { lhs.next(); } else { rhs.next(); }
+        }
+
+        // the ranges of each set are separated, so the pieces of them are too
+        Self { ranges }
+    }
+
+    /// Every value that's in this set but not `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for &(start, end) in &self.ranges {
+            // skip the ranges that end before this one starts
+            while others.next_if(|&&(_, other_end)| other_end < start).is_some() {}
+
+            let mut remaining = Some(start);
+            for &(other_start, other_end) in others.clone().take_while(|&&(other_start, _)| other_start <= end) {
+                let Some(start) = remaining else { break };
+
+                if other_start > start {
+                    ranges.push((start, other_start.predecessor().expect("there's a value before other_start")));
+                }
+                remaining = other_end.successor().filter(|&next| next <= end);
+            }
+
+            if let Some(start) = remaining { ranges.push((start, end)) }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        Self::normalise(ranges.into_iter().map(RangeInclusive::into_inner).collect())
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        ranges.into_iter().for_each(|range| self.insert(range));
+    }
+}
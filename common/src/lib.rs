@@ -1,14 +1,127 @@
+mod args;
+mod compress;
+mod disjoint_set;
+mod grid;
+mod interval;
+mod polygon;
+mod spatial;
+pub mod text;
+pub use args::{Arg, ArgSpec, Arguments, ArgumentsError, help};
+pub use compress::{CompressedGrid, CoordinateCompressor};
+pub use disjoint_set::DisjointSet;
+pub use grid::{Automaton, Boundary, Grid, GridDisplay, GridFromStrError, Neighbourhood, Neighbours};
+pub use interval::{Discrete, IntervalSet};
+pub use polygon::{PolygonError, RectilinearPolygon};
+pub use spatial::{ClosestPairs, KdTree, MinimumSpanningTree};
+
 use std::collections::HashMap;
-use std::env::args;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::File;
 use std::io::{Read, Stdin, stdin};
 use std::str::FromStr;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum FromRegexCapturesError {
     MissingField(&'static str),
-    FailedToParse(&'static str),
+    FailedToParse {
+        field: &'static str,
+        /// the text captured for the field
+        text: String,
+        /// the byte offset of the text in the haystack the regex searched
+        offset: usize,
+        source: Box<dyn Error + Send + Sync>,
+    },
     Malformed(&'static str),
     RegexDidntMatch,
+    /// strict parsing found text that isn't a match or a separator
+    UnmatchedInput,
+}
+
+impl FromRegexCapturesError {
+    pub fn failed_to_parse(field: &'static str, m: &regex::Match, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::FailedToParse { field, text: m.as_str().to_string(), offset: m.start(), source: source.into() }
+    }
+}
+
+impl Display for FromRegexCapturesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "capture group `{field}` didn't participate in the match"),
+            Self::FailedToParse { field, text, source, .. } => write!(f, "failed to parse `{field}` from {text:?}: {source}"),
+            Self::Malformed(reason) => write!(f, "{reason}"),
+            Self::RegexDidntMatch => write!(f, "the regex didn't match"),
+            Self::UnmatchedInput => write!(f, "unexpected input between matches"),
+        }
+    }
+}
+
+impl Error for FromRegexCapturesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::FailedToParse { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// A `FromRegexCapturesError` from parsing part of a larger input, with where it happened
+#[derive(Debug)]
+pub struct ParseError {
+    /// the line the error happened on, starting from 1
+    pub line: usize,
+    /// the byte offset into the line that the error happened at, starting from 1
+    pub column: usize,
+    /// all the text the regex matched, or the unmatched text for `UnmatchedInput`
+    pub matched: String,
+    pub error: FromRegexCapturesError,
+}
+
+impl ParseError {
+    fn new(haystack: &str, m: &regex::Match, error: FromRegexCapturesError) -> Self {
+        // point at the field that failed if we know it, otherwise the start of the match
+        let offset = match &error {
+            FromRegexCapturesError::FailedToParse { offset, .. } => *offset,
+            _ => m.start(),
+        };
+
+        Self::at(haystack, offset, m.as_str(), error)
+    }
+
+    /// The text from `start` up to the next match or the end of its line, which strict parsing couldn't account for
+    fn unmatched(haystack: &str, start: usize, next_match: Option<usize>) -> Self {
+        let rest = &haystack[start..];
+        let end = rest.find('\n').unwrap_or(rest.len()).min(next_match.map_or(rest.len(), |next| next - start));
+
+        Self::at(haystack, start, &rest[..end], FromRegexCapturesError::UnmatchedInput)
+    }
+
+    fn at(haystack: &str, offset: usize, matched: &str, error: FromRegexCapturesError) -> Self {
+        let line_start = haystack[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: haystack[..offset].matches('\n').count() + 1,
+            column: offset - line_start + 1,
+            matched: matched.to_string(),
+            error,
+        }
+    }
+
+    /// Moves the error down by `count` lines, for when the haystack was part of a larger input
+    fn offset_lines(mut self, count: usize) -> Self {
+        self.line += count;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: {} (while parsing {:?})", self.line, self.column, self.error, self.matched)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> { Some(&self.error) }
 }
 
 #[allow(unused)]
@@ -18,62 +131,178 @@ pub trait FromRegexCaptures
     fn from_regex_captures(captures: &regex::Captures) -> Result<Self, FromRegexCapturesError>;
 }
 
+/// A type that knows the regex it's parsed from, usually implemented with `#[from_regex(r"...")]` on the
+/// `FromRegexCaptures` derive
+pub trait FromRegex: FromRegexCaptures {
+    fn regex() -> &'static regex::Regex;
+
+    /// Parses every match of the type's regex in `str`, reporting matches that fail to parse
+    fn parse_all(str: &str) -> impl Iterator<Item = Result<Self, ParseError>> {
+        str.try_iter_by_regex(Self::regex())
+    }
+
+    /// Parses `str` as matches of the type's regex with only separators between them, see `try_iter_by_regex_strict`
+    fn parse_all_strict(str: &str, is_separator: fn(char) -> bool) -> impl Iterator<Item = Result<Self, ParseError>> {
+        str.try_iter_by_regex_strict(Self::regex(), is_separator)
+    }
+
+    /// Parses section `index` of the input as matches of the type's regex, with only whitespace between them
+    fn parse_section(sections: &mut Sections, index: usize) -> Result<Box<[Self]>, SectionError> {
+        sections.take(index)?
+            .try_iter_by_regex_strict(Self::regex(), char::is_whitespace)
+            .collect::<Result<_, _>>()
+            .map_err(SectionError::Parse)
+    }
+
+    /// Parses `str`, which must match the type's regex exactly
+    fn parse_exact(str: &str) -> Result<Self, FromRegexCapturesError> {
+        let captures = Self::regex().captures(str).ok_or(FromRegexCapturesError::RegexDidntMatch)?;
+        let m = captures.get_match();
+        if m.start() != 0 || m.end() != str.len() { return Err(FromRegexCapturesError::RegexDidntMatch) }
+        Self::from_regex_captures(&captures)
+    }
+}
+
 pub struct RegexStringIterator<'a, 'b, T: FromRegexCaptures> {
     str: &'a str,
+    position: usize,
     regex: &'b regex::Regex,
+    mode: MatchMode,
     _phantom_t: std::marker::PhantomData<T>,
 }
 
-pub trait IterByRegex<'a> {
-    fn iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = T>;
+/// Where matches of the regex may be found in the input
+#[derive(Clone, Copy)]
+pub enum MatchMode {
+    /// anywhere, skipping over any text between matches
+    Anywhere,
+    /// one after another, with only characters that are separators between them.
+    /// Any other text is reported as an error, and ends the iteration
+    Contiguous { is_separator: fn(char) -> bool },
 }
 
-impl<'a, 'b> IterByRegex<'a> for &'b str {
+pub trait IterByRegex<'a> where Self: Sized {
+    /// Parses each match of the regex found as described by `mode`, reporting any that fail to parse
+    fn try_iter_by_regex_in<T: FromRegexCaptures>(self, regex: &'a regex::Regex, mode: MatchMode) -> impl Iterator<Item = Result<T, ParseError>>;
+
+    /// Parses each match of the regex, reporting any that fail to parse
+    fn try_iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = Result<T, ParseError>> {
+        self.try_iter_by_regex_in(regex, MatchMode::Anywhere)
+    }
+
+    /// Parses each match of the regex, reporting any that fail to parse, and any text between the matches that
+    /// isn't a separator. Line based inputs are split into lines first, so newlines are always separators
+    fn try_iter_by_regex_strict<T: FromRegexCaptures>(self, regex: &'a regex::Regex, is_separator: fn(char) -> bool) -> impl Iterator<Item = Result<T, ParseError>> {
+        self.try_iter_by_regex_in(regex, MatchMode::Contiguous { is_separator })
+    }
+
+    /// Parses each match of the regex, stopping at the first one that fails to parse
     fn iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = T> {
-        RegexStringIterator { str: self, regex, _phantom_t: Default::default() }
+        self.try_iter_by_regex(regex).map_while(Result::ok)
     }
 }
 
-impl<'a, 'b> IterByRegex<'a> for &'b String {
-    fn iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = T> {
-        RegexStringIterator { str: self.as_str(), regex, _phantom_t: Default::default() }
+impl<'a> IterByRegex<'a> for &str {
+    fn try_iter_by_regex_in<T: FromRegexCaptures>(self, regex: &'a regex::Regex, mode: MatchMode) -> impl Iterator<Item = Result<T, ParseError>> {
+        RegexStringIterator { str: self, position: 0, regex, mode, _phantom_t: Default::default() }
+    }
+}
+
+impl<'a> IterByRegex<'a> for &String {
+    fn try_iter_by_regex_in<T: FromRegexCaptures>(self, regex: &'a regex::Regex, mode: MatchMode) -> impl Iterator<Item = Result<T, ParseError>> {
+        self.as_str().try_iter_by_regex_in(regex, mode)
     }
 }
 
 impl<'a> IterByRegex<'a> for Stdin {
-    fn iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = T> {
+    fn try_iter_by_regex_in<T: FromRegexCaptures>(self, regex: &'a regex::Regex, mode: MatchMode) -> impl Iterator<Item = Result<T, ParseError>> {
         self.lines()
-            .flatten()
-            .map(|line| line.iter_by_regex(regex).collect::<Vec<T>>())
-            .flatten()
+            .map_while(Result::ok)
+            .enumerate()
+            .flat_map(move |(index, line)| line.try_iter_by_regex_in(regex, mode)
+                .map(|result| result.map_err(|e| e.offset_lines(index)))
+                .collect::<Vec<_>>())
     }
 }
 
-impl<'a, 'b> IterByRegex<'a> for &'b mut Input {
-    fn iter_by_regex<T: FromRegexCaptures>(self, regex: &'a regex::Regex) -> impl Iterator<Item = T> {
+impl<'a> IterByRegex<'a> for &mut Input {
+    fn try_iter_by_regex_in<T: FromRegexCaptures>(self, regex: &'a regex::Regex, mode: MatchMode) -> impl Iterator<Item = Result<T, ParseError>> {
+        let lines_before = self.first_line() + self.lines_read();
+
         self.lines()
-            .map(|line| line.iter_by_regex(regex).collect::<Vec<T>>())
-            .flatten()
+            .enumerate()
+            .flat_map(move |(index, line)| line.try_iter_by_regex_in(regex, mode)
+                .map(|result| result.map_err(|e| e.offset_lines(lines_before + index)))
+                .collect::<Vec<_>>())
     }
 }
 
 impl<'a, 'b, T> Iterator for RegexStringIterator<'a, 'b, T>
     where T: FromRegexCaptures
 {
-    type Item = T;
+    type Item = Result<T, ParseError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let captures = self.regex.captures(self.str)?;
-        let t = T::from_regex_captures(&captures).ok()?;
-        self.str = &self.str[captures.get_match().end()..];
-        Some(t)
+        if self.position > self.str.len() { return None }
+
+        if let MatchMode::Contiguous { is_separator } = self.mode {
+            let rest = &self.str[self.position..];
+            self.position += rest.len() - rest.trim_start_matches(is_separator).len();
+            if self.position == self.str.len() { return None }
+        }
+
+        let captures = self.regex.captures_at(self.str, self.position);
+
+        if let MatchMode::Contiguous { .. } = self.mode {
+            let next_match = captures.as_ref().map(|captures| captures.get_match());
+
+            // an empty match wouldn't consume anything, so it can't account for the text here either
+            if next_match.is_none_or(|m| m.start() != self.position || m.is_empty()) {
+                let error = ParseError::unmatched(self.str, self.position, next_match.map(|m| m.start()));
+                self.position = self.str.len() + 1;
+                return Some(Err(error));
+            }
+        }
+
+        let captures = captures?;
+        let m = captures.get_match();
+
+        // step over empty matches so we don't find them again
+        self.position = if m.is_empty() {
+            m.end() + self.str[m.end()..].chars().next().map_or(1, char::len_utf8)
+        } else {
+            m.end()
+        };
+
+        Some(T::from_regex_captures(&captures).map_err(|error| ParseError::new(self.str, &m, error)))
     }
 }
 
 #[derive(Debug)]
 pub enum GetInputError {
-    FileDoesNotExist { path: String },
-    FailedToReadFile,
+    FileDoesNotExist { path: String, error: std::io::Error },
+    FailedToReadFile { path: String, error: std::io::Error },
+    /// a line in the input's `.params` file isn't `name=value`
+    MalformedParams { path: String, line: usize },
+}
+
+impl Display for GetInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileDoesNotExist { path, .. } => write!(f, "the input file {path} doesn't exist"),
+            Self::FailedToReadFile { path, error } => write!(f, "failed to read {path}: {error}"),
+            Self::MalformedParams { path, line } => write!(f, "line {line} of {path} isn't name=value"),
+        }
+    }
+}
+
+impl Error for GetInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::FileDoesNotExist { error, .. } | Self::FailedToReadFile { error, .. } => Some(error),
+            Self::MalformedParams { .. } => None,
+        }
+    }
 }
 
 pub enum Input {
@@ -81,65 +310,178 @@ pub enum Input {
     String {
         str: String,
         lines_read: usize,
+        /// how many lines of the original input came before this one, if it's a section of a larger input
+        first_line: usize,
+        /// the puzzle parameters from the input's `.params` file
+        params: HashMap<String, String>,
     }
 }
 
-pub struct Arguments {
-    pub named: HashMap<String, String>,
-    pub unnamed: Vec<String>
+/// Which part of the puzzle to solve, selected with `part=1`, `part=2` or `part=both`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    Both,
 }
 
-static mut ARGUMENTS: Option<Arguments> = None;
+impl FromStr for Part {
+    type Err = String;
 
-impl Arguments {
-    #[allow(static_mut_refs)]
-    fn get() -> &'static Self {
-        if unsafe { ARGUMENTS.is_none() } {
-            let mut result = Self { named: HashMap::new(), unnamed: Vec::new() };
-            let mut args = args().skip(1);
-            let named_arg_regex = regex::Regex::new("(?<key>.*)=(?<value>.*)").unwrap();
-            
-            while let Some(arg) = args.next() {
-                if let Some(named_arg) = named_arg_regex.captures(&arg) {
-                    let key = named_arg.name("key").unwrap().as_str().to_string();
-                    let value = named_arg.name("value").unwrap().as_str().to_string();
-                    result.named.insert(key, value);
-                } else {
-                    result.unnamed.push(arg);
-                }
-            }
-            
-            unsafe { ARGUMENTS = Some(result); }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err("expected 1, 2 or both".to_string()),
         }
-        
-        unsafe { ARGUMENTS.as_ref().unwrap() }
     }
+}
 
-    pub fn get_named<T: FromStr>(name: &str) -> Option<T> {
-        Self::get().named.get(name)?.parse().ok()
-    }
+pub const PART: Arg<Part> = Arg::new("part", "which part to solve: 1, 2 or both").default("both");
+
+pub const INPUT: Arg<String> = Arg::new("input", "the path of the puzzle input, or - for stdin. Can also be given unnamed");
+
+/// The options every binary that solves puzzles accepts
+pub const COMMON_ARGS: [ArgSpec; 2] = [PART.spec(), INPUT.spec()];
+
+impl Part {
+    /// Gets the part selected on the command line, defaulting to both parts
+    pub fn get() -> Self { PART.value() }
+
+    pub fn runs_part1(&self) -> bool { matches!(self, Part::One | Part::Both) }
+    pub fn runs_part2(&self) -> bool { matches!(self, Part::Two | Part::Both) }
 }
 
+/// Gets the puzzle input from the path given as the first unnamed argument, or as `input=...`.
+/// Reads from stdin if no path is given, or if the path is `-`
 pub fn get_input() -> Result<Input, GetInputError> {
-    // if let Some(path) = args().skip(1).next() {
-    //     let mut buffer = String::new();
-    //     let mut file = File::open(&path).map_err(|_| GetInputError::FileDoesNotExist { path })?;
-    //     file.read_to_string(&mut buffer).map_err(|_| GetInputError::FailedToReadFile)?;
-    //     Ok(Input::String{ str: buffer, lines_read: 0 })
-    // } else {
-    //     Ok(Input::Stdin)
-    // }
+    let path = INPUT.get()
+        .or_else(|| Arguments::get().unnamed().first().cloned());
 
-    Ok(Input::Stdin)
+    match path {
+        None => Ok(Input::Stdin),
+        Some(path) if path == "-" => Ok(Input::Stdin),
+        Some(path) => Input::from_file(path),
+    }
+}
+
+/// A day's puzzle, split into parsing the input and solving each part from the parsed input
+pub trait Solution {
+    const DAY: u8;
+
+    /// The options the solution reads from the command line, besides the common ones
+    const ARGS: &'static [ArgSpec] = &[];
+
+    type Parsed;
+
+    fn parse(input: Input) -> Result<Self::Parsed, Box<dyn Error>>;
+    fn part1(parsed: &Self::Parsed) -> Result<impl Display, Box<dyn Error>>;
+    fn part2(parsed: &Self::Parsed) -> Result<impl Display, Box<dyn Error>>;
 }
 
 impl Input {
-    pub fn new(str: String) -> Self { Self::String { str, lines_read: 0 } }
+    pub fn new(str: String) -> Self { Self::String { str, lines_read: 0, first_line: 0, params: HashMap::new() } }
+
+    /// A puzzle parameter for this input, from the `.params` file next to it
+    pub fn param(&self, name: &str) -> Option<&str> {
+        match self {
+            Input::Stdin => None,
+            Input::String { params, .. } => params.get(name).map(String::as_str),
+        }
+    }
+
+    /// How many lines have already been read from the input, as far as we know
+    pub fn lines_read(&self) -> usize {
+        match self {
+            Input::Stdin => 0,
+            Input::String { lines_read, .. } => *lines_read,
+        }
+    }
+
+    /// How many lines of the original input came before this one, which is only non zero for sections
+    pub fn first_line(&self) -> usize {
+        match self {
+            Input::Stdin => 0,
+            Input::String { first_line, .. } => *first_line,
+        }
+    }
+
+    /// Splits the rest of the input into the blocks of lines between blank lines, each as its own input.
+    /// Reads all of stdin first, since the sections can't share it
+    pub fn sections(self) -> Sections {
+        let lines_before = self.first_line() + self.lines_read();
+        let params = match &self {
+            Input::Stdin => HashMap::new(),
+            Input::String { params, .. } => params.clone(),
+        };
+        let str = self.collect_to_string();
+        let section = |str, first_line| Some(Input::String { str, lines_read: 0, first_line, params: params.clone() });
+
+        let mut sections = Vec::new();
+        let mut current = String::new();
+        let mut first_line = lines_before;
+
+        for (index, line) in str.lines().enumerate() {
+            if line.trim().is_empty() {
+                if !current.is_empty() {
+                    sections.push(section(std::mem::take(&mut current), first_line));
+                }
+                first_line = lines_before + index + 1;
+            } else {
+                current.push_str(line);
+                current.push('\n');
+            }
+        }
+
+        if !current.is_empty() {
+            sections.push(section(current, first_line));
+        }
+
+        Sections { sections }
+    }
+
+    /// Reads the input from a file, along with the puzzle parameters from the file with the same name and a
+    /// `.params` extension if there is one. Each line of that is `name=value`, and blank lines and lines starting
+    /// with `#` are ignored
+    pub fn from_file(path: String) -> Result<Self, GetInputError> {
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Err(GetInputError::FileDoesNotExist { path, error }),
+            Err(error) => return Err(GetInputError::FailedToReadFile { path, error }),
+        };
+
+        let mut buffer = String::new();
+        if let Err(error) = file.read_to_string(&mut buffer) {
+            return Err(GetInputError::FailedToReadFile { path, error });
+        }
+
+        let params_path = std::path::Path::new(&path).with_extension("params");
+        let params = match std::fs::read_to_string(&params_path) {
+            Ok(params) => Self::parse_params(&params)
+                .map_err(|line| GetInputError::MalformedParams { path: params_path.display().to_string(), line })?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(GetInputError::FailedToReadFile { path: params_path.display().to_string(), error }),
+        };
+
+        Ok(Self::String { str: buffer, lines_read: 0, first_line: 0, params })
+    }
+
+    /// Parses the lines of a `.params` file, or returns the number of the first line that's malformed
+    fn parse_params(text: &str) -> Result<HashMap<String, String>, usize> {
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(index, line)| line.split_once('=')
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .ok_or(index + 1))
+            .collect()
+    }
 
     pub fn lines<'a>(&'a mut self) -> InputLines<'a> {
         match self {
             Input::Stdin => InputLines::Stdin(stdin().lines()),
-            Input::String{ str, lines_read } => InputLines::File {
+            Input::String{ str, lines_read, .. } => InputLines::File {
                 iter: str.lines().skip(*lines_read),
                 lines_read,
             },
@@ -153,7 +495,7 @@ impl Input {
                 stdin().read_to_string(&mut buffer).expect("File io error while reading input");
                 buffer
             },
-            Input::String { str, lines_read } => {
+            Input::String { str, lines_read, .. } => {
                 str.lines().skip(lines_read)
                     .map(|line| format!("{line}\n"))
                     .collect()
@@ -177,87 +519,222 @@ impl<'a> Iterator for InputLines<'a> {
         match self {
             InputLines::Stdin(lines) => lines.next().into_iter().flatten().next(),
             InputLines::File { iter, lines_read } => {
+                let line = iter.next()?;
                 **lines_read += 1;
-                iter.next().map(|s| s.into())
+                Some(line.into())
             }
         }
     }
 }
 
-pub struct WrapAround<T: Clone, I: Iterator<Item = T>> {
-    buffer: Vec<Option<T>>,
-    index: isize,
-    iter: I,
+/// The blank line separated sections of an input, from `Input::sections`
+pub struct Sections {
+    sections: Vec<Option<Input>>,
 }
 
-pub trait WrapAroundAble<T: Clone> where Self: Iterator<Item = T> + Sized {
-    /// Creates an iterator that repeats the first `count` many items returned by this iterator
-    fn wrap_around(self, count: usize) -> WrapAround<T, Self> {
-        WrapAround { buffer: vec![None; count], index: 0, iter: self }
+impl Sections {
+    pub fn len(&self) -> usize { self.sections.len() }
+    pub fn is_empty(&self) -> bool { self.sections.is_empty() }
+
+    /// Takes the section at `index`, which can only be done once for each section
+    pub fn take(&mut self, index: usize) -> Result<Input, SectionError> {
+        let count = self.sections.len();
+        self.sections.get_mut(index)
+            .ok_or(SectionError::Missing { index, count })?
+            .take()
+            .ok_or(SectionError::AlreadyTaken(index))
     }
 }
 
-impl<T: Clone, I: Iterator<Item = T>> WrapAroundAble<T> for I {}
+impl IntoIterator for Sections {
+    type Item = Input;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<Input>>>;
 
-impl<T: Clone, I: Iterator<Item = T>> Iterator for WrapAround<T, I> {
+    fn into_iter(self) -> Self::IntoIter { self.sections.into_iter().flatten() }
+}
+
+#[derive(Debug)]
+pub enum SectionError {
+    Missing { index: usize, count: usize },
+    AlreadyTaken(usize),
+    Parse(ParseError),
+}
+
+impl Display for SectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { index, count } => write!(f, "expected at least {} sections, but the input only has {count}", index + 1),
+            Self::AlreadyTaken(index) => write!(f, "section {index} was already taken"),
+            Self::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for SectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An iterator that yields every item, then carries on around from the start for `count` more items, as if the
+/// items were in a circle. From `CircularIterator::cycle_prefix`
+pub struct CyclePrefix<T: Clone, I: Iterator<Item = T>> {
+    prefix: Vec<T>,
+    count: usize,
+    /// how many items have been repeated, once the source has run out
+    repeated: Option<usize>,
+    iter: I,
+}
+
+impl<T: Clone, I: Iterator<Item = T>> Iterator for CyclePrefix<T, I> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            Some(item) => {
-                if self.index < self.buffer.len() as isize {
-                    self.buffer[self.index as usize] = Some(item.clone());
-                    self.index += 1;
-                }
-                Some(item)
+        if self.repeated.is_none() {
+            if let Some(item) = self.iter.next() {
+                if self.prefix.len() < self.count { self.prefix.push(item.clone()) }
+                return Some(item);
+            }
+
+            self.repeated = Some(0);
+        }
+
+        let repeated = self.repeated.as_mut()?;
+        if *repeated == self.count || self.prefix.is_empty() { return None }
+
+        // if there were fewer than `count` items, keep going around the circle
+        let item = self.prefix[*repeated % self.prefix.len()].clone();
+        *repeated += 1;
+        Some(item)
+    }
+}
+
+pub trait CircularIterator<T: Clone> where Self: Iterator<Item = T> + Sized {
+    /// Creates an iterator that yields every item, then repeats the first `count` items in order. If there are fewer
+    /// than `count` items, it keeps going around them until it's repeated `count`
+    fn cycle_prefix(self, count: usize) -> CyclePrefix<T, Self> {
+        CyclePrefix { prefix: Vec::with_capacity(count), count, repeated: None, iter: self }
+    }
+
+    /// Creates an iterator over each item and the one after it, with the last item paired with the first,
+    /// like the edges of a polygon
+    fn circular_pairs(self) -> Pairs<T, CyclePrefix<T, Self>> {
+        self.cycle_prefix(1).pairs()
+    }
+
+    /// Creates an iterator over the `N` items starting at each item, wrapping around to the start at the end.
+    /// There are as many windows as items, even if there are fewer than `N` items
+    fn circular_windows<const N: usize>(self) -> WindowIter<T, CyclePrefix<T, Self>, N> {
+        self.cycle_prefix(N.saturating_sub(1)).windows::<N>()
+    }
+}
+
+impl<T: Clone, I: Iterator<Item = T>> CircularIterator<T> for I {}
+
+pub struct WindowIter<T: Clone, I: Iterator<Item = T>, const N: usize> {
+    window: Option<[T; N]>,
+    iter: I,
+}
+
+impl<T: Clone, I: Iterator<Item = T>, const N: usize> Iterator for WindowIter<T, I, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                // slide the window along by one, overwriting the oldest item
+                let item = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = item;
             },
             None => {
-                if self.index > 0 && self.index <= self.buffer.len() as isize {
-                    let mut result = None;
-                    let count = self.buffer.len();
-                    std::mem::swap(&mut self.buffer[count - self.index as usize], &mut result);
-                    self.index -= 1;
-                    result
-                } else {
-                    None
-                }
+                let items: Vec<T> = self.iter.by_ref().take(N).collect();
+                self.window = Some(items.try_into().ok()?);
             },
         }
+
+        self.window.clone()
+    }
+}
+
+/// Consecutive pairs of items, from `WindowAble::pairs`
+pub struct Pairs<T: Clone, I: Iterator<Item = T>> {
+    previous: Option<T>,
+    iter: I,
+}
+
+impl<T: Clone, I: Iterator<Item = T>> Iterator for Pairs<T, I> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+
+        let next = self.iter.next()?;
+        self.previous = Some(next.clone());
+        Some((previous, next))
     }
 }
 
-pub struct WindowIter<T: Clone, I: Iterator<Item = T>> {
+/// Windows that borrow from a buffer instead of copying the items, from `WindowAble::slice_windows`.
+/// This can't be an `Iterator`, since each window borrows from the iterator itself
+pub struct SliceWindows<T, I: Iterator<Item = T>> {
+    /// holds up to two windows worth of items, so the buffer is only shifted down once every `window_size` items
     buffer: Vec<T>,
     window_size: usize,
     iter: I,
 }
 
-impl<T: Clone, I: Iterator<Item = T>> Iterator for WindowIter<T, I> {
-    type Item = Vec<T>;
+impl<T, I: Iterator<Item = T>> SliceWindows<T, I> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[T]> {
+        if self.buffer.len() == 2 * self.window_size {
+            self.buffer.drain(..self.window_size);
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        while self.buffer.len() < self.window_size {
+        if self.buffer.len() < self.window_size {
+            // the first window
+            self.buffer.extend(self.iter.by_ref().take(self.window_size - self.buffer.len()));
+            if self.buffer.len() < self.window_size { return None }
+        } else {
             self.buffer.push(self.iter.next()?);
         }
 
-        let result = Some(self.buffer.clone());
-        self.buffer.rotate_left(1);
-        self.buffer.pop();
-        result
+        Some(&self.buffer[self.buffer.len() - self.window_size..])
     }
 }
 
-pub trait WindowAble<T> where T: Clone, Self: Iterator<Item = T> + Sized {
-    /// Creates an iterator that outputs vectors containing `window_size` consecutive items returned by this iterator
-    fn windows(self, window_size: usize) -> WindowIter<T, Self> {
-        WindowIter { buffer: Vec::new(), window_size, iter: self }
+pub trait WindowAble<T> where Self: Iterator<Item = T> + Sized {
+    /// Creates an iterator that outputs arrays containing `N` consecutive items returned by this iterator
+    fn windows<const N: usize>(self) -> WindowIter<T, Self, N> where T: Clone {
+        assert!(N > 0, "windows need at least one item");
+        WindowIter { window: None, iter: self }
+    }
+
+    /// Creates an iterator over each item and the one after it
+    fn pairs(self) -> Pairs<T, Self> where T: Clone {
+        Pairs { previous: None, iter: self }
+    }
+
+    /// Creates a lending iterator over slices of `window_size` consecutive items returned by this iterator,
+    /// without cloning any of them
+    fn slice_windows(self, window_size: usize) -> SliceWindows<T, Self> {
+        assert!(window_size > 0, "windows need at least one item");
+        SliceWindows { buffer: Vec::with_capacity(2 * window_size), window_size, iter: self }
     }
 }
 
-impl<T: Clone, I: Iterator<Item = T> + Sized> WindowAble<T> for I {}
+impl<T, I: Iterator<Item = T> + Sized> WindowAble<T> for I {}
 
+/// The items of `First`, then the items of `Second`. From `IteratorCombinators::prepend` and `append`
 pub struct ConcatIterator<T, First: Iterator<Item = T>, Second: Iterator<Item = T>> {
-    first: First,
+    /// `None` once it runs out, so it's not polled again
+    first: Option<First>,
     second: Second,
 }
 
@@ -265,21 +742,90 @@ impl<T, First: Iterator<Item = T>, Second: Iterator<Item = T>> Iterator for Conc
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.first.next().or_else(|| self.second.next())
+        if let Some(first) = &mut self.first {
+            match first.next() {
+                Some(item) => return Some(item),
+                None => self.first = None,
+            }
+        }
+
+        self.second.next()
     }
 }
 
-pub trait PrependIterator<T, I: Iterator<Item = T>> where Self: Iterator<Item = T> + Sized {
-    fn prepend(self, other: I) -> ConcatIterator<T, Self, I> {
-        ConcatIterator { first: self, second: other }
+/// The items with a copy of `separator` between each pair of them. From `IteratorCombinators::interspersed`
+pub struct Interspersed<T: Clone, I: Iterator<Item = T>> {
+    separator: T,
+    /// the item after the next separator, once we've looked ahead for it
+    next_item: Option<T>,
+    started: bool,
+    iter: std::iter::Fuse<I>,
+}
+
+impl<T: Clone, I: Iterator<Item = T>> Iterator for Interspersed<T, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return self.iter.next();
+        }
+
+        if let Some(item) = self.next_item.take() {
+            return Some(item);
+        }
+
+        // only yield a separator if there's an item to go after it
+        self.next_item = Some(self.iter.next()?);
+        Some(self.separator.clone())
     }
 }
 
-pub trait AppendIterator<T, I: Iterator<Item = T>> where Self: Iterator<Item = T> + Sized {
-    fn append(self, other: I) -> ConcatIterator<T, I, Self> {
-        ConcatIterator { first: other, second: self }
+/// Items taken from each iterator in turn, then the rest of the longer one. From `IteratorCombinators::interleave`
+pub struct Interleave<T, A: Iterator<Item = T>, B: Iterator<Item = T>> {
+    a: std::iter::Fuse<A>,
+    b: std::iter::Fuse<B>,
+    /// whether the next item comes from `b`
+    next_from_b: bool,
+}
+
+impl<T, A: Iterator<Item = T>, B: Iterator<Item = T>> Iterator for Interleave<T, A, B> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = if self.next_from_b {
+            self.b.next().or_else(|| self.a.next())
+        } else {
+            self.a.next().or_else(|| self.b.next())
+        };
+
+        self.next_from_b = !self.next_from_b;
+        item
+    }
+}
+
+/// Ways of combining iterators with other items
+pub trait IteratorCombinators<T> where Self: Iterator<Item = T> + Sized {
+    /// Creates an iterator that yields the items of `other`, then the items of this iterator
+    fn prepend<I: IntoIterator<Item = T>>(self, other: I) -> ConcatIterator<T, I::IntoIter, Self> {
+        ConcatIterator { first: Some(other.into_iter()), second: self }
+    }
+
+    /// Creates an iterator that yields the items of this iterator, then the items of `other`
+    fn append<I: IntoIterator<Item = T>>(self, other: I) -> ConcatIterator<T, Self, I::IntoIter> {
+        ConcatIterator { first: Some(self), second: other.into_iter() }
+    }
+
+    /// Creates an iterator that yields a copy of `separator` between each item of this iterator
+    fn interspersed(self, separator: T) -> Interspersed<T, Self> where T: Clone {
+        Interspersed { separator, next_item: None, started: false, iter: self.fuse() }
+    }
+
+    /// Creates an iterator that alternates between the items of this iterator and `other`, starting with this
+    /// iterator. Once either runs out, the rest of the other one follows
+    fn interleave<I: IntoIterator<Item = T>>(self, other: I) -> Interleave<T, Self, I::IntoIter> {
+        Interleave { a: self.fuse(), b: other.into_iter().fuse(), next_from_b: false }
     }
 }
 
-impl<T, First: Iterator<Item = T>, Second: Iterator<Item = T>> PrependIterator<T, Second> for First {}
-impl<T, First: Iterator<Item = T>, Second: Iterator<Item = T>> AppendIterator<T, First> for Second {}
+impl<T, I: Iterator<Item = T>> IteratorCombinators<T> for I {}
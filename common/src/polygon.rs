@@ -0,0 +1,103 @@
+use crate::{CircularIterator, CoordinateCompressor, Grid};
+use std::error::Error;
+use std::fmt::Display;
+
+/// A polygon with only horizontal and vertical edges, drawn through the tiles at its vertices. It contains the tiles
+/// on its edges as well as those inside them.
+///
+/// Coordinates are `(x, y)`. The coordinates are compressed into blocks that are either all in or all out of the
+/// polygon, with the vertex rows and columns on their own, so containment is answered by counting outside blocks
+/// with prefix sums
+#[derive(Clone, Debug)]
+pub struct RectilinearPolygon {
+    xs: CoordinateCompressor,
+    ys: CoordinateCompressor,
+    /// `outside[(row, col)]` is how many blocks before `row` and `col` are outside the polygon
+    outside: Grid<usize>,
+}
+
+impl RectilinearPolygon {
+    /// The polygon with these vertices in order, the last one joined back to the first
+    pub fn new(vertices: &[(i64, i64)]) -> Result<Self, PolygonError> {
+        if vertices.is_empty() { return Err(PolygonError::Empty) }
+
+        let edges: Box<[_]> = vertices.iter().copied().circular_pairs().collect();
+
+        if let Some(&(from, to)) = edges.iter().find(|((x1, y1), (x2, y2))| x1 != x2 && y1 != y2) {
+            return Err(PolygonError::NotRectilinear { from, to });
+        }
+
+        let xs = CoordinateCompressor::with_neighbours(vertices.iter().map(|&(x, _)| x));
+        let ys = CoordinateCompressor::with_neighbours(vertices.iter().map(|&(_, y)| y));
+        let vertex_blocks = |compressor: &CoordinateCompressor, a: i64, b: i64|
+            compressor.blocks(a.min(b), a.max(b)).expect("the vertices are all compressed");
+
+        let (width, height) = (xs.len(), ys.len());
+        let mut on_edge = Grid::from_fn(width, height, |_| false);
+        // for each row, the columns of the vertical edges that a tile to their right crosses going left
+        let mut crossings = Grid::from_fn(width, height, |_| false);
+
+        for &((x1, y1), (x2, y2)) in &edges {
+            let columns = vertex_blocks(&xs, x1, x2);
+            let rows = vertex_blocks(&ys, y1, y2);
+
+            for row in rows.clone() {
+                for col in columns.clone() { on_edge[(row, col)] = true }
+            }
+
+            // counting a vertical edge for the rows from its top up to but not including its bottom means a row going
+            // along a horizontal edge crosses either both or neither of the edges at its ends
+            if x1 == x2 && y1 != y2 {
+                for row in rows.take_while(|&row| ys.decompress(row) < y1.max(y2)) {
+                    crossings[(row, columns.start)] ^= true;
+                }
+            }
+        }
+
+        let mut outside = Grid::from_fn(width + 1, height + 1, |_| 0);
+        for row in 0..height {
+            let mut inside = false;
+            for col in 0..width {
+                let is_outside = !inside && !on_edge[(row, col)];
+                outside[(row + 1, col + 1)] = outside[(row, col + 1)] + outside[(row + 1, col)] - outside[(row, col)]
+                    + usize::from(is_outside);
+                inside ^= crossings[(row, col)];
+            }
+        }
+
+        Ok(Self { xs, ys, outside })
+    }
+
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        self.contains_rectangle((x, y), (x, y))
+    }
+
+    /// Whether every tile of the rectangle with these opposite corners is in the polygon
+    pub fn contains_rectangle(&self, (x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> bool {
+        let columns = self.xs.blocks(x1.min(x2), x1.max(x2));
+        let rows = self.ys.blocks(y1.min(y2), y1.max(y2));
+        let (Some(columns), Some(rows)) = (columns, rows) else { return false };
+
+        let outside = self.outside[(rows.end, columns.end)] + self.outside[(rows.start, columns.start)]
+            - self.outside[(rows.start, columns.end)] - self.outside[(rows.end, columns.start)];
+        outside == 0
+    }
+}
+
+#[derive(Debug)]
+pub enum PolygonError {
+    Empty,
+    NotRectilinear { from: (i64, i64), to: (i64, i64) },
+}
+
+impl Display for PolygonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "a polygon needs at least one vertex"),
+            Self::NotRectilinear { from: (x1, y1), to: (x2, y2) } =>
+                write!(f, "the edge from {x1},{y1} to {x2},{y2} isn't horizontal or vertical"),
+        }
+    }
+}
+
+impl Error for PolygonError {}
@@ -0,0 +1,176 @@
+use crate::DisjointSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+/// How many neighbours of each point `ClosestPairs` looks for at first. It looks for twice as many each time they
+/// run out
+const INITIAL_NEIGHBOURS: usize = 8;
+
+/// A k-d tree over points in `K` dimensions, for finding each point's nearest neighbours without comparing it to
+/// every other point. Points are referred to by their index in the order they were given
+#[derive(Clone, Debug)]
+pub struct KdTree<const K: usize> {
+    points: Vec<[i64; K]>,
+    /// the indices of the points, arranged so that the middle of each range is the median of that range along the
+    /// axis for its depth, with the smaller points before it and the larger after
+    order: Vec<usize>,
+}
+
+impl<const K: usize> KdTree<K> {
+    pub fn new(points: impl IntoIterator<Item = [i64; K]>) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let mut order: Vec<_> = (0..points.len()).collect();
+        arrange(&points, &mut order, 0);
+
+        Self { points, order }
+    }
+
+    pub fn len(&self) -> usize { self.points.len() }
+    pub fn is_empty(&self) -> bool { self.points.is_empty() }
+    pub fn points(&self) -> &[[i64; K]] { &self.points }
+
+    pub fn sqr_distance(lhs: &[i64; K], rhs: &[i64; K]) -> u64 {
+        lhs.iter().zip(rhs).map(|(lhs, rhs)| lhs.abs_diff(*rhs).pow(2)).sum()
+    }
+
+    /// The `k` points closest to the point at `index`, not including itself, as `(squared distance, index)` ordered
+    /// by distance and then by index
+    pub fn nearest(&self, index: usize, k: usize) -> Vec<(u64, usize)> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 { self.search(index, 0..self.order.len(), 0, k, &mut best) }
+        best.into_sorted_vec()
+    }
+
+    /// Adds the closest points in `range` of `order` to `best`, which is kept to the `k` closest found so far
+    fn search(&self, index: usize, range: Range<usize>, depth: usize, k: usize, best: &mut BinaryHeap<(u64, usize)>) {
+        if range.is_empty() { return }
+
+        let middle = range.start + range.len() / 2;
+        let node = self.order[middle];
+        let target = &self.points[index];
+
+        if node != index {
+            let candidate = (Self::sqr_distance(target, &self.points[node]), node);
+            if best.len() < k {
+                best.push(candidate);
+            } else if best.peek().is_some_and(|&furthest| candidate < furthest) {
+                best.pop();
+                best.push(candidate);
+            }
+        }
+
+        // look on the target's side of the split first, since that's where the closest points probably are
+        let offset = target[depth % K] - self.points[node][depth % K];
+        let (near, far) = if offset < 0 {
+            (range.start..middle, middle + 1..range.end)
+        } else {
+            (middle + 1..range.end, range.start..middle)
+        };
+
+        self.search(index, near, depth + 1, k, best);
+
+        // everything on the other side is at least as far away as the split
+        let split_distance = offset.unsigned_abs().pow(2);
+        if best.len() < k || best.peek().is_some_and(|&(furthest, _)| split_distance <= furthest) {
+            self.search(index, far, depth + 1, k, best);
+        }
+    }
+
+    /// Every unique pair of points, from closest to furthest, with ties ordered by index. The pairs are found as
+    /// they're needed, so taking the first few is cheap even when there are far too many pairs to store
+    pub fn closest_pairs(&self) -> ClosestPairs<'_, K> {
+        let mut pairs = ClosestPairs {
+            tree: self,
+            neighbours: vec![(Vec::new(), 0); self.len()],
+            queue: BinaryHeap::with_capacity(self.len()),
+        };
+
+        (0..self.len()).for_each(|index| pairs.advance(index));
+        pairs
+    }
+
+    /// The edges of a minimum spanning tree connecting every point, using Kruskal's algorithm on the closest pairs.
+    /// The edges come out in order of length, so the last one is the longest
+    pub fn minimum_spanning_tree(&self) -> MinimumSpanningTree<'_, K> {
+        MinimumSpanningTree { pairs: self.closest_pairs(), sets: DisjointSet::new(self.len()) }
+    }
+}
+
+/// Arranges `order` into a k-d tree, splitting on the axis for `depth`
+fn arrange<const K: usize>(points: &[[i64; K]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 { return }
+
+    let middle = order.len() / 2;
+    order.select_nth_unstable_by_key(middle, |&index| points[index][depth % K]);
+
+    let (before, after) = order.split_at_mut(middle);
+    arrange(points, before, depth + 1);
+    arrange(points, &mut after[1..], depth + 1);
+}
+
+/// The unique pairs of points in a `KdTree`, from closest to furthest, as `(lower index, higher index)`
+pub struct ClosestPairs<'a, const K: usize> {
+    tree: &'a KdTree<K>,
+    /// for each point, the nearest neighbours found so far and how many of them have been queued
+    neighbours: Vec<(Vec<(u64, usize)>, usize)>,
+    /// the next unused neighbour of each point, as `(squared distance, lower index, higher index, point)`
+    queue: BinaryHeap<Reverse<(u64, usize, usize, usize)>>,
+}
+
+impl<const K: usize> ClosestPairs<'_, K> {
+    /// Queues the next neighbour of the point at `index`, finding more neighbours if it's used them all up
+    fn advance(&mut self, index: usize) {
+        let (found, used) = &mut self.neighbours[index];
+        let others = self.tree.len() - 1;
+
+        if *used == found.len() && found.len() < others {
+            // the nearest neighbours are ordered by index as well as distance, so the first ones are the same as before
+            let k = (found.len() * 2).max(INITIAL_NEIGHBOURS).min(others);
+            *found = self.tree.nearest(index, k);
+        }
+
+        if let Some(&(distance, neighbour)) = found.get(*used) {
+            *used += 1;
+            self.queue.push(Reverse((distance, index.min(neighbour), index.max(neighbour), index)));
+        }
+    }
+}
+
+impl<const K: usize> Iterator for ClosestPairs<'_, K> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((_, lhs, rhs, index)) = self.queue.pop()?;
+            self.advance(index);
+
+            // each pair is found from both ends, so only use it from the lower index
+            if index == lhs { return Some((lhs, rhs)) }
+        }
+    }
+}
+
+/// The edges of a minimum spanning tree over the points in a `KdTree`, from shortest to longest
+pub struct MinimumSpanningTree<'a, const K: usize> {
+    pairs: ClosestPairs<'a, K>,
+    sets: DisjointSet,
+}
+
+impl<const K: usize> MinimumSpanningTree<'_, K> {
+    /// Which points the edges so far have connected
+    pub fn sets(&self) -> &DisjointSet { &self.sets }
+}
+
+impl<const K: usize> Iterator for MinimumSpanningTree<'_, K> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.sets.components() > 1 {
+            let (lhs, rhs) = self.pairs.next()?;
+            if self.sets.union(lhs, rhs) { return Some((lhs, rhs)) }
+        }
+
+        None
+    }
+}
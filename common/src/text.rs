@@ -0,0 +1,50 @@
+//! Parsing for text laid out in fixed-width columns, by treating it as a grid of characters
+
+use crate::Grid;
+use std::str::FromStr;
+
+/// Pads each line with spaces to the length of the longest line, so the text can be indexed by column
+pub fn pad_lines(s: &str) -> Grid<char> {
+    let lines: Box<[Box<[char]>]> = s.lines().map(|line| line.chars().collect()).collect();
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    Grid::from_fn(width, lines.len(), |(row, col)| lines[row].get(col).copied().unwrap_or(' '))
+}
+
+/// Splits the text into blocks of columns, separated by columns that are entirely whitespace
+pub fn column_blocks(text: &Grid<char>) -> Vec<Grid<char>> {
+    let blank_columns: Box<[bool]> = text.columns()
+        .map(|mut column| column.all(|char| char.is_whitespace()))
+        .collect();
+
+    let mut blocks = Vec::new();
+    let mut block_start = None;
+
+    // the column past the end counts as blank, to end the last block
+    for col in 0..=text.width() {
+        match (block_start, blank_columns.get(col).copied().unwrap_or(true)) {
+            (None, false) => block_start = Some(col),
+            (Some(start), true) => {
+                blocks.push(text.sub_grid(0..text.height(), start..col));
+                block_start = None;
+            },
+            _ => {},
+        }
+    }
+
+    blocks
+}
+
+/// Reads each column from top to bottom as a number, ignoring whitespace and skipping blank columns
+pub fn vertical_numbers<T: FromStr>(text: &Grid<char>) -> Result<Vec<T>, T::Err> {
+    text.columns()
+        .map(|column| column.filter(|char| !char.is_whitespace()).collect::<String>())
+        .filter(|digits| !digits.is_empty())
+        .map(|digits| digits.parse())
+        .collect()
+}
+
+/// Reads each row from left to right as a number, ignoring whitespace and skipping blank rows
+pub fn horizontal_numbers<T: FromStr>(text: &Grid<char>) -> Result<Vec<T>, T::Err> {
+    vertical_numbers(&text.transpose())
+}
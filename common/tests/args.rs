@@ -0,0 +1,81 @@
+use aoc_2025_common::*;
+
+const COUNT: Arg<usize> = Arg::new("count", "how many").default("3");
+const NAME: Arg<String> = Arg::new("name", "a name");
+
+fn parse(args: &[&str], options: Option<&[ArgSpec]>) -> Result<Arguments, ArgumentsError> {
+    Arguments::parse(args.iter().map(|arg| arg.to_string()), options)
+}
+
+#[test]
+fn splits_named_and_unnamed() {
+    let arguments = parse(&["input.txt", "count=10", "name=", "more"], None).unwrap();
+
+    assert_eq!(arguments.named("count"), Some("10"));
+    assert_eq!(arguments.named("name"), Some(""));
+    assert_eq!(arguments.named("missing"), None);
+    assert_eq!(arguments.unnamed(), ["input.txt", "more"]);
+}
+
+#[test]
+fn only_identifiers_are_names() {
+    let arguments = parse(&["some/path=1.txt", "-x=1", "input=a=b"], None).unwrap();
+
+    assert_eq!(arguments.unnamed(), ["some/path=1.txt", "-x=1"]);
+    assert_eq!(arguments.named("input"), Some("a=b"));
+}
+
+#[test]
+fn checks_names_and_values_against_the_options() {
+    let options = [COUNT.spec(), NAME.spec()];
+
+    assert!(parse(&["count=10", "name=x"], Some(&options)).is_ok());
+    assert!(matches!(parse(&["cont=10"], Some(&options)), Err(ArgumentsError::Unknown(name)) if name == "cont"));
+    assert!(matches!(
+        parse(&["count=ten"], Some(&options)),
+        Err(ArgumentsError::InvalidValue { name: "count", value, .. }) if value == "ten"
+    ));
+}
+
+#[test]
+fn value_in_prefers_the_command_line_then_the_input_then_the_default() {
+    let params_input = |params: &str| {
+        let directory = std::env::temp_dir().join(format!("aoc_2025_common_args_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("input.txt"), "").unwrap();
+        std::fs::write(directory.join("input.params"), params).unwrap();
+        let input = Input::from_file(directory.join("input.txt").display().to_string()).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        input
+    };
+
+    let command_line = parse(&["count=7"], None).unwrap();
+    assert_eq!(COUNT.value_in(&command_line, &params_input("count=10")).unwrap(), 7);
+    assert_eq!(COUNT.value_in(&command_line, &Input::new(String::new())).unwrap(), 7);
+
+    let empty_command_line = parse(&[], None).unwrap();
+    assert_eq!(COUNT.value_in(&empty_command_line, &Input::new(String::new())).unwrap(), 3);
+    assert_eq!(COUNT.value_in(&empty_command_line, &params_input("count=10")).unwrap(), 10);
+    assert!(matches!(COUNT.value_in(&empty_command_line, &params_input("count=x")), Err(ArgumentsError::InvalidValue { .. })));
+    assert!(matches!(NAME.value_in(&empty_command_line, &Input::new(String::new())), Err(ArgumentsError::Missing { name: "name", .. })));
+}
+
+#[test]
+fn help_lists_every_option() {
+    let help = help("test [input]", &[COUNT.spec(), NAME.spec()]);
+
+    assert!(help.starts_with("usage: test [input]\n"));
+    assert!(help.contains("  count=  how many (default: 3)\n"));
+    assert!(help.contains("  name=   a name\n"));
+    assert!(help.contains("  --help  "));
+}
+
+#[test]
+fn part_parses_its_names() {
+    assert_eq!("1".parse(), Ok(Part::One));
+    assert_eq!("2".parse(), Ok(Part::Two));
+    assert_eq!("both".parse(), Ok(Part::Both));
+    assert!("3".parse::<Part>().is_err());
+    assert!(Part::Both.runs_part1() && Part::Both.runs_part2());
+    assert!(!Part::One.runs_part2() && !Part::Two.runs_part1());
+}
@@ -0,0 +1,73 @@
+mod support;
+
+use aoc_2025_common::CircularIterator;
+use support::check;
+
+/// The items, followed by `count` more going around from the start
+fn naive_cycle_prefix(items: &[usize], count: usize) -> Vec<usize> {
+    let repeated = if items.is_empty() { 0 } else { count };
+    items.iter().copied().chain((0..repeated).map(|index| items[index % items.len()])).collect()
+}
+
+fn naive_circular_windows<const N: usize>(items: &[usize]) -> Vec<[usize; N]> {
+    (0..items.len())
+        .map(|start| std::array::from_fn(|offset| items[(start + offset) % items.len()]))
+        .collect()
+}
+
+#[test]
+fn cycle_prefix_keeps_order() {
+    assert_eq!([1, 2, 3, 4].into_iter().cycle_prefix(3).collect::<Vec<_>>(), [1, 2, 3, 4, 1, 2, 3]);
+}
+
+#[test]
+fn cycle_prefix_goes_around_short_sources() {
+    assert_eq!([1, 2].into_iter().cycle_prefix(5).collect::<Vec<_>>(), [1, 2, 1, 2, 1, 2, 1]);
+    assert_eq!(std::iter::empty::<usize>().cycle_prefix(5).count(), 0);
+}
+
+#[test]
+fn circular_pairs_close_the_polygon() {
+    assert_eq!([1, 2, 3].into_iter().circular_pairs().collect::<Vec<_>>(), [(1, 2), (2, 3), (3, 1)]);
+    assert_eq!([1].into_iter().circular_pairs().collect::<Vec<_>>(), [(1, 1)]);
+}
+
+#[test]
+fn cycle_prefix_matches_naive() {
+    check(500, |rng| {
+        let items = rng.vec(20, 100);
+        let count = rng.below(30);
+        assert_eq!(items.iter().copied().cycle_prefix(count).collect::<Vec<_>>(), naive_cycle_prefix(&items, count),
+            "items {items:?}, count {count}");
+    });
+}
+
+#[test]
+fn cycle_prefix_matches_cycle_take() {
+    check(500, |rng| {
+        let items = rng.vec(20, 100);
+        let count = rng.below(items.len() + 1);
+        let expected: Vec<_> = items.iter().copied().cycle().take(items.len() + count).collect();
+        assert_eq!(items.iter().copied().cycle_prefix(count).collect::<Vec<_>>(), expected, "items {items:?}, count {count}");
+    });
+}
+
+#[test]
+fn circular_pairs_match_naive() {
+    check(500, |rng| {
+        let items = rng.vec(20, 100);
+        let expected: Vec<_> = (0..items.len()).map(|index| (items[index], items[(index + 1) % items.len()])).collect();
+        assert_eq!(items.iter().copied().circular_pairs().collect::<Vec<_>>(), expected, "items {items:?}");
+    });
+}
+
+#[test]
+fn circular_windows_match_naive() {
+    check(500, |rng| {
+        let items = rng.vec(10, 100);
+        assert_eq!(items.iter().copied().circular_windows::<1>().collect::<Vec<_>>(), naive_circular_windows::<1>(&items), "items {items:?}");
+        assert_eq!(items.iter().copied().circular_windows::<2>().collect::<Vec<_>>(), naive_circular_windows::<2>(&items), "items {items:?}");
+        assert_eq!(items.iter().copied().circular_windows::<3>().collect::<Vec<_>>(), naive_circular_windows::<3>(&items), "items {items:?}");
+        assert_eq!(items.iter().copied().circular_windows::<5>().collect::<Vec<_>>(), naive_circular_windows::<5>(&items), "items {items:?}");
+    });
+}
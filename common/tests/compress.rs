@@ -0,0 +1,87 @@
+mod support;
+
+use aoc_2025_common::{CompressedGrid, CoordinateCompressor};
+use support::check;
+
+#[test]
+fn compresses_in_order() {
+    let compressor = CoordinateCompressor::new([100, -5, 40, 100]);
+
+    assert_eq!(compressor.len(), 3);
+    assert_eq!(compressor.compress(-5), Some(0));
+    assert_eq!(compressor.compress(100), Some(2));
+    assert_eq!(compressor.compress(41), None);
+    assert_eq!((0..3).map(|index| compressor.decompress(index)).collect::<Vec<_>>(), [-5, 40, 100]);
+}
+
+#[test]
+fn blocks_run_to_the_next_value() {
+    let compressor = CoordinateCompressor::new([0, 10, 12]);
+
+    assert_eq!(compressor.block(-1), None);
+    assert_eq!(compressor.block(0), Some(0));
+    assert_eq!(compressor.block(9), Some(0));
+    assert_eq!(compressor.block(11), Some(1));
+    assert_eq!(compressor.block(12), Some(2));
+    assert_eq!(compressor.block(13), None);
+
+    assert_eq!((0..3).map(|index| compressor.width(index)).collect::<Vec<_>>(), [10, 2, 1]);
+    assert_eq!(compressor.blocks(5, 12), Some(0..3));
+    assert_eq!(compressor.blocks(5, 13), None);
+}
+
+#[test]
+fn neighbours_keep_the_gaps() {
+    let compressor = CoordinateCompressor::with_neighbours([3, 10, 4]);
+
+    assert_eq!((0..compressor.len()).map(|index| compressor.range(index)).collect::<Vec<_>>(), [
+        2..3, 3..4, 4..5, 5..9, 9..10, 10..11, 11..12,
+    ]);
+    assert_eq!(compressor.block(7), Some(3));
+}
+
+#[test]
+fn every_coordinate_is_in_its_block() {
+    check(100, |rng| {
+        let values: Vec<i64> = rng.vec(10, 50).into_iter().map(|value| value as i64 - 25).collect();
+        let compressor = CoordinateCompressor::with_neighbours(values.iter().copied());
+
+        for &value in &values {
+            let index = compressor.compress(value).unwrap();
+            assert_eq!(compressor.decompress(index), value);
+            assert_eq!(compressor.width(index), 1);
+        }
+
+        if compressor.is_empty() { return }
+
+        let (first, last) = (compressor.decompress(0), compressor.range(compressor.len() - 1).end);
+        for value in first - 2..last + 2 {
+            let block = compressor.block(value);
+            assert_eq!(block.is_some(), (first..last).contains(&value), "{value} in {values:?}");
+            assert!(block.is_none_or(|block| compressor.range(block).contains(&value)));
+        }
+
+        let total: u64 = (0..compressor.len()).map(|index| compressor.width(index)).sum();
+        assert_eq!(total, last.abs_diff(first));
+    });
+}
+
+#[test]
+fn grid_cells_are_weighted_by_area() {
+    let xs = CoordinateCompressor::with_neighbours([0, 10]);
+    let ys = CoordinateCompressor::with_neighbours([0, 4]);
+
+    // a rectangle from 0,0 to 10,4
+    let grid = CompressedGrid::from_fn(xs, ys, |(x, y)| (0..=10).contains(&x) && (0..=4).contains(&y));
+
+    assert_eq!(grid.cells.width(), 6);
+    assert_eq!(grid.cells.height(), 6);
+    assert_eq!(grid.get((5, 2)), Some(&true));
+    assert_eq!(grid.get((11, 2)), Some(&false));
+    assert_eq!(grid.get((12, 2)), None);
+    assert_eq!(grid.position((5, 2)), Some((2, 2)));
+    assert_eq!(grid.area((2, 2)), 8 * 2);
+
+    assert_eq!(grid.area_where(|&inside| inside), 11 * 5);
+    assert_eq!(grid.areas().cells().sum::<u64>(), 13 * 7);
+}
@@ -0,0 +1,64 @@
+mod support;
+
+use aoc_2025_common::DisjointSet;
+use support::check;
+
+/// Labels every element with the smallest element it's connected to, by relabelling on each union
+fn naive_labels(len: usize, unions: &[(usize, usize)]) -> Vec<usize> {
+    let mut labels: Vec<usize> = (0..len).collect();
+    for &(a, b) in unions {
+        let (from, to) = (labels[a].max(labels[b]), labels[a].min(labels[b]));
+        labels.iter_mut().filter(|label| **label == from).for_each(|label| *label = to);
+    }
+    labels
+}
+
+#[test]
+fn starts_with_singletons() {
+    let mut set = DisjointSet::new(4);
+    assert_eq!(set.components(), 4);
+    assert_eq!(set.size_of(2), 1);
+    assert!(!set.same_set(0, 1));
+    assert_eq!(set.sets().collect::<Vec<_>>(), [vec![0], vec![1], vec![2], vec![3]]);
+}
+
+#[test]
+fn union_reports_whether_it_merged() {
+    let mut set = DisjointSet::new(5);
+    assert!(set.union(0, 1));
+    assert!(set.union(3, 1));
+    assert!(!set.union(0, 3));
+    assert_eq!(set.components(), 3);
+    assert_eq!(set.size_of(3), 3);
+
+    let mut sizes: Vec<_> = set.component_sizes().collect();
+    sizes.sort();
+    assert_eq!(sizes, [1, 1, 3]);
+    assert_eq!(set.sets().collect::<Vec<_>>(), [vec![0, 1, 3], vec![2], vec![4]]);
+}
+
+#[test]
+fn matches_naive_labelling() {
+    check(200, |rng| {
+        let len = rng.below(30) + 1;
+        let unions: Vec<_> = rng.vec(80, len).chunks_exact(2).map(|pair| (pair[0], pair[1])).collect();
+
+        let mut set = DisjointSet::new(len);
+        unions.iter().for_each(|&(a, b)| { set.union(a, b); });
+        let labels = naive_labels(len, &unions);
+
+        for a in 0..len {
+            for b in 0..len {
+                assert_eq!(set.same_set(a, b), labels[a] == labels[b], "{a} and {b} after {unions:?}");
+            }
+            assert_eq!(set.size_of(a), labels.iter().filter(|&&label| label == labels[a]).count());
+        }
+
+        let mut expected: Vec<usize> = labels.clone();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(set.components(), expected.len());
+        assert_eq!(set.component_sizes().sum::<usize>(), len);
+        assert_eq!(set.sets().map(|set| set[0]).collect::<Vec<_>>(), expected);
+    });
+}
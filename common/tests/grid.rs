@@ -0,0 +1,66 @@
+use aoc_2025_common::*;
+
+fn grid(s: &str) -> Grid<char> {
+    Grid::parse_with(s, Some).unwrap()
+}
+
+#[test]
+fn parses_rows_of_cells() {
+    let grid = grid("abc\ndef\n");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [['a', 'b', 'c'], ['d', 'e', 'f']]);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+}
+
+#[test]
+fn reports_bad_grids() {
+    assert!(matches!(Grid::parse_with("ab\nc", Some), Err(GridFromStrError::InconsistentWidth { row: 1, expected: 2, actual: 1 })));
+    assert!(matches!(Grid::parse_with("", Some), Err(GridFromStrError::Empty)));
+    assert!(matches!(
+        Grid::parse_with("..\n.#", |c| (c == '.').then_some(())),
+        Err(GridFromStrError::UnexpectedCharacter { row: 1, col: 1, char: '#' })
+    ));
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = grid("abc\ndef\nghi");
+
+    let neighbours = |cells: Vec<(_, &char)>| cells.into_iter().map(|(_, &c)| c).collect::<String>();
+    assert_eq!(neighbours(grid.neighbours4((0, 0)).collect()), "bd");
+    assert_eq!(neighbours(grid.neighbours8((1, 1)).collect()), "abcdfghi");
+    assert_eq!(neighbours(grid.neighbours8((2, 2)).collect()), "efh");
+}
+
+#[test]
+fn boundaries_decide_the_neighbours_of_edges() {
+    let grid = grid("abc\ndef\nghi");
+
+    let neighbours = |boundary| grid.neighbours_in((0, 0), Neighbourhood::VonNeumann, boundary).collect::<String>();
+    assert_eq!(neighbours(Boundary::Empty), "bd");
+    assert_eq!(neighbours(Boundary::Wrap), "gcbd");
+    assert_eq!(neighbours(Boundary::Clamp), "aabd");
+    assert_eq!(*grid.get_wrapping((-1, 4)), 'h');
+}
+
+#[test]
+fn steps_read_the_previous_generation() {
+    // every cell becomes its left neighbour. Updating in place would smear the first cell along the whole row
+    let mut automaton = Automaton::new(grid("abcd"), Neighbourhood::Custom(&[(0, -1)]), Boundary::Wrap);
+    automaton.step(|_, mut neighbours| *neighbours.next().unwrap());
+    assert_eq!(automaton.grid().to_string(), "dabc\n");
+}
+
+#[test]
+fn run_until_stable_counts_the_generations_that_changed() {
+    // a cell fills in if the cell to its left is filled
+    let mut automaton = Automaton::new(grid("#...."), Neighbourhood::Custom(&[(0, -1)]), Boundary::Empty);
+    let generations = automaton.run_until_stable(|&cell, mut neighbours| if neighbours.next() == Some(&'#') { '#' } else { cell });
+
+    assert_eq!(generations, 4);
+    assert_eq!(automaton.grid().to_string(), "#####\n");
+}
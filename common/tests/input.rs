@@ -0,0 +1,77 @@
+use aoc_2025_common::*;
+
+fn input(str: &str) -> Input { Input::new(str.to_string()) }
+
+#[test]
+fn lines_carry_on_from_where_they_stopped() {
+    let mut input = input("a\nb\nc\nd\n");
+
+    assert_eq!(input.lines().take(2).collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(input.lines_read(), 2);
+
+    assert_eq!(input.lines().collect::<Vec<_>>(), ["c", "d"]);
+    assert_eq!(input.lines_read(), 4);
+}
+
+#[test]
+fn exhausted_lines_arent_counted() {
+    let mut input = input("a\nb");
+
+    assert_eq!(input.lines().count(), 2);
+    assert_eq!(input.lines().next(), None);
+    assert_eq!(input.lines().next(), None);
+    assert_eq!(input.lines_read(), 2);
+}
+
+#[test]
+fn collect_to_string_skips_lines_already_read() {
+    let mut input = input("a\nb\nc");
+    input.lines().next();
+    assert_eq!(input.collect_to_string(), "b\nc\n");
+}
+
+#[test]
+fn sections_split_on_blank_lines() {
+    let sections: Vec<(usize, String)> = input("\na\nb\n\n  \nc\n\n")
+        .sections()
+        .into_iter()
+        .map(|section| (section.first_line(), section.collect_to_string()))
+        .collect();
+
+    assert_eq!(sections, [(1, "a\nb\n".to_string()), (5, "c\n".to_string())]);
+}
+
+#[test]
+fn sections_start_from_the_lines_not_read_yet() {
+    let mut input = input("header\n\na\n\nb\n");
+    input.lines().next();
+
+    let first_lines: Vec<usize> = input.sections().into_iter().map(|section| section.first_line()).collect();
+    assert_eq!(first_lines, [2, 4]);
+}
+
+#[test]
+fn from_file_reads_params_next_to_the_input() {
+    let directory = std::env::temp_dir().join(format!("aoc_2025_common_input_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let path = directory.join("example.txt");
+    std::fs::write(&path, "1\n2\n").unwrap();
+    std::fs::write(directory.join("example.params"), "# a comment\n\ncount = 10\n").unwrap();
+
+    let input = Input::from_file(path.display().to_string()).unwrap();
+    assert_eq!(input.param("count"), Some("10"));
+    assert_eq!(input.param("other"), None);
+
+    std::fs::write(directory.join("example.params"), "count\n").unwrap();
+    let error = Input::from_file(path.display().to_string()).err();
+    assert!(matches!(error, Some(GetInputError::MalformedParams { line: 1, .. })));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn from_file_reports_missing_files() {
+    let error = Input::from_file("this/file/does/not/exist.txt".to_string()).err();
+    assert!(matches!(error, Some(GetInputError::FileDoesNotExist { .. })));
+}
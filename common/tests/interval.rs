@@ -0,0 +1,92 @@
+mod support;
+
+use aoc_2025_common::IntervalSet;
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use support::{Rng, check};
+
+/// Up to 8 ranges of small numbers, some of them empty
+fn random_ranges(rng: &mut Rng) -> Vec<RangeInclusive<i32>> {
+    let ends = rng.vec(16, 30);
+    ends.chunks_exact(2).map(|ends| ends[0] as i32..=ends[0] as i32 + ends[1] as i32 / 3 - 3).collect()
+}
+
+fn values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+    set.iter().flatten().collect()
+}
+
+/// Checks that the ranges are sorted and separated by at least one value
+fn assert_normalised(set: &IntervalSet<i32>) {
+    let ranges: Vec<_> = set.iter().collect();
+    assert!(ranges.iter().all(|range| range.start() <= range.end()), "{ranges:?}");
+    assert!(ranges.windows(2).all(|pair| pair[0].end() + 1 < *pair[1].start()), "{ranges:?}");
+}
+
+#[test]
+fn merges_overlapping_and_touching_ranges() {
+    let set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18, 6..=6, RangeInclusive::new(30, 29)].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [3..=6, 10..=20]);
+    assert_eq!(set.total_len(), 15);
+
+    let mut set = IntervalSet::new();
+    set.insert(0..=u8::MAX);
+    set.insert(255..=255);
+    assert_eq!(set.total_len(), 256);
+}
+
+#[test]
+fn contains_at_the_edges() {
+    let set: IntervalSet<u64> = [3..=5, 10..=14].into_iter().collect();
+    assert_eq!((0..16).filter(|&id| set.contains(id)).collect::<Vec<_>>(), [3, 4, 5, 10, 11, 12, 13, 14]);
+}
+
+#[test]
+fn set_operations() {
+    let lhs: IntervalSet<i64> = [0..=10, 20..=30].into_iter().collect();
+    let rhs: IntervalSet<i64> = [5..=22, 25..=25, 40..=50].into_iter().collect();
+
+    assert_eq!(lhs.union(&rhs).iter().collect::<Vec<_>>(), [0..=30, 40..=50]);
+    assert_eq!(lhs.intersection(&rhs).iter().collect::<Vec<_>>(), [5..=10, 20..=22, 25..=25]);
+    assert_eq!(lhs.difference(&rhs).iter().collect::<Vec<_>>(), [0..=4, 23..=24, 26..=30]);
+    assert_eq!(rhs.difference(&lhs).iter().collect::<Vec<_>>(), [11..=19, 40..=50]);
+}
+
+#[test]
+fn insert_matches_naive() {
+    check(200, |rng| {
+        let ranges = random_ranges(rng);
+
+        let mut set = IntervalSet::new();
+        let mut naive = BTreeSet::new();
+        for range in &ranges {
+            set.insert(range.clone());
+            naive.extend(range.clone());
+
+            assert_normalised(&set);
+            assert_eq!(values(&set), naive, "after {ranges:?}");
+        }
+
+        assert_eq!(set, ranges.iter().cloned().collect());
+        assert_eq!(set.total_len(), naive.len() as u128);
+        assert!((-5..50).all(|value| set.contains(value) == naive.contains(&value)));
+    });
+}
+
+#[test]
+fn operations_match_naive() {
+    check(200, |rng| {
+        let lhs: IntervalSet<i32> = random_ranges(rng).into_iter().collect();
+        let rhs: IntervalSet<i32> = random_ranges(rng).into_iter().collect();
+        let (lhs_values, rhs_values) = (values(&lhs), values(&rhs));
+
+        let union = lhs.union(&rhs);
+        let intersection = lhs.intersection(&rhs);
+        let difference = lhs.difference(&rhs);
+
+        for set in [&union, &intersection, &difference] { assert_normalised(set) }
+
+        assert_eq!(values(&union), &lhs_values | &rhs_values, "{lhs:?} | {rhs:?}");
+        assert_eq!(values(&intersection), &lhs_values & &rhs_values, "{lhs:?} & {rhs:?}");
+        assert_eq!(values(&difference), &lhs_values - &rhs_values, "{lhs:?} - {rhs:?}");
+    });
+}
@@ -0,0 +1,92 @@
+mod support;
+
+use aoc_2025_common::*;
+use support::check;
+
+#[test]
+fn windows_match_slice_windows() {
+    check(500, |rng| {
+        let items = rng.vec(20, 100);
+
+        let expected: Vec<[usize; 3]> = items.windows(3).map(|window| window.try_into().unwrap()).collect();
+        assert_eq!(items.iter().copied().windows::<3>().collect::<Vec<_>>(), expected, "items {items:?}");
+
+        let expected: Vec<[usize; 1]> = items.windows(1).map(|window| window.try_into().unwrap()).collect();
+        assert_eq!(items.iter().copied().windows::<1>().collect::<Vec<_>>(), expected, "items {items:?}");
+    });
+}
+
+#[test]
+fn slice_windows_match_slice_windows() {
+    check(500, |rng| {
+        let items = rng.vec(30, 100);
+        let size = rng.below(6) + 1;
+
+        let mut windows = items.iter().copied().slice_windows(size);
+        let mut actual = Vec::new();
+        while let Some(window) = windows.next() { actual.push(window.to_vec()) }
+
+        let expected: Vec<Vec<usize>> = items.windows(size).map(<[usize]>::to_vec).collect();
+        assert_eq!(actual, expected, "items {items:?}, size {size}");
+    });
+}
+
+#[test]
+fn pairs_match_slice_windows() {
+    check(500, |rng| {
+        let items = rng.vec(20, 100);
+        let expected: Vec<(usize, usize)> = items.windows(2).map(|pair| (pair[0], pair[1])).collect();
+        assert_eq!(items.iter().copied().pairs().collect::<Vec<_>>(), expected, "items {items:?}");
+    });
+}
+
+#[test]
+fn prepend_and_append_match_chain() {
+    check(500, |rng| {
+        let (first, second) = (rng.vec(10, 100), rng.vec(10, 100));
+        let chained: Vec<usize> = first.iter().chain(&second).copied().collect();
+
+        assert_eq!(first.iter().copied().append(second.iter().copied()).collect::<Vec<_>>(), chained);
+        assert_eq!(second.iter().copied().prepend(first.iter().copied()).collect::<Vec<_>>(), chained);
+    });
+}
+
+#[test]
+fn prepend_and_append_take_single_items() {
+    assert_eq!((2..4).prepend([1]).append(Some(4)).collect::<Vec<_>>(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn interspersed_puts_separators_between_items() {
+    assert_eq!(["a", "b", "c"].into_iter().interspersed(", ").collect::<String>(), "a, b, c");
+    assert_eq!(["a"].into_iter().interspersed(", ").collect::<String>(), "a");
+    assert_eq!(std::iter::empty::<&str>().interspersed(", ").count(), 0);
+}
+
+#[test]
+fn interspersed_matches_naive() {
+    check(500, |rng| {
+        let items = rng.vec(10, 100);
+        let expected: Vec<usize> = items.iter()
+            .enumerate()
+            .flat_map(|(index, &item)| if index == 0 { vec![item] } else { vec![1000, item] })
+            .collect();
+
+        assert_eq!(items.iter().copied().interspersed(1000).collect::<Vec<_>>(), expected, "items {items:?}");
+    });
+}
+
+#[test]
+fn interleave_matches_naive() {
+    check(500, |rng| {
+        let (a, b) = (rng.vec(10, 100), rng.vec(10, 100));
+
+        let mut expected = Vec::new();
+        for index in 0..a.len().max(b.len()) {
+            expected.extend(a.get(index));
+            expected.extend(b.get(index));
+        }
+
+        assert_eq!(a.iter().copied().interleave(b.iter().copied()).collect::<Vec<_>>(), expected, "a {a:?}, b {b:?}");
+    });
+}
@@ -0,0 +1,103 @@
+mod support;
+
+use aoc_2025_common::RectilinearPolygon;
+use support::check;
+
+/// A U, open at the top
+const NOTCH: [(i64, i64); 8] = [(0, 0), (10, 0), (10, 10), (7, 10), (7, 3), (3, 3), (3, 10), (0, 10)];
+
+/// The example from day 9, which goes in at both ends
+const EXAMPLE: [(i64, i64); 8] = [(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)];
+
+/// A spiral going in clockwise
+const SPIRAL: [(i64, i64); 12] = [
+    (0, 0), (12, 0), (12, 12), (4, 12), (4, 6), (8, 6), (8, 8), (6, 8), (6, 10), (10, 10), (10, 2), (0, 2),
+];
+
+/// An L, going round anticlockwise
+const L: [(i64, i64); 6] = [(0, 0), (0, 8), (8, 8), (8, 6), (2, 6), (2, 0)];
+
+/// Whether a tile is in the polygon, by checking whether it's on an edge and otherwise casting a ray to the left
+fn naive_contains(vertices: &[(i64, i64)], (x, y): (i64, i64)) -> bool {
+    let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+
+    let on_edge = edges().any(|(&(x1, y1), &(x2, y2))|
+        (x1.min(x2)..=x1.max(x2)).contains(&x) && (y1.min(y2)..=y1.max(y2)).contains(&y));
+
+    let crossings = edges()
+        .filter(|&(&(x1, y1), &(x2, y2))| x1 == x2 && x1 < x && y1.min(y2) <= y && y < y1.max(y2))
+        .count();
+
+    on_edge || crossings % 2 == 1
+}
+
+fn naive_contains_rectangle(vertices: &[(i64, i64)], (x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> bool {
+    (x1.min(x2)..=x1.max(x2)).all(|x| (y1.min(y2)..=y1.max(y2)).all(|y| naive_contains(vertices, (x, y))))
+}
+
+#[test]
+fn rejects_rectangles_across_a_notch() {
+    let polygon = RectilinearPolygon::new(&NOTCH).unwrap();
+
+    // these only touch the edges of the notch, so they don't overlap any edge
+    assert!(!polygon.contains_rectangle((0, 10), (10, 10)));
+    assert!(!polygon.contains_rectangle((3, 10), (7, 10)));
+    assert!(!polygon.contains_rectangle((3, 3), (7, 10)));
+    assert!(!polygon.contains((5, 5)));
+
+    assert!(polygon.contains_rectangle((0, 0), (10, 3)));
+    assert!(polygon.contains_rectangle((7, 3), (10, 10)));
+    assert!(polygon.contains_rectangle((3, 3), (7, 3)));
+}
+
+#[test]
+fn rejects_tiles_outside_the_bounds() {
+    let polygon = RectilinearPolygon::new(&EXAMPLE).unwrap();
+
+    assert!(polygon.contains_rectangle((9, 5), (2, 3)));
+    assert!(!polygon.contains_rectangle((11, 1), (2, 5)));
+    assert!(!polygon.contains((12, 1)));
+    assert!(!polygon.contains((1, 4)));
+    assert!(!polygon.contains((-1, -1)));
+}
+
+#[test]
+fn single_tiles_and_lines() {
+    let tile = RectilinearPolygon::new(&[(3, 4)]).unwrap();
+    assert!(tile.contains((3, 4)));
+    assert!(!tile.contains((3, 5)));
+
+    let line = RectilinearPolygon::new(&[(0, 2), (5, 2)]).unwrap();
+    assert!(line.contains_rectangle((0, 2), (5, 2)));
+    assert!(!line.contains_rectangle((0, 2), (5, 3)));
+}
+
+#[test]
+fn rejects_diagonal_edges() {
+    assert!(RectilinearPolygon::new(&[(0, 0), (2, 0), (2, 2), (1, 3)]).is_err());
+    assert!(RectilinearPolygon::new(&[]).is_err());
+}
+
+#[test]
+fn matches_naive_on_concave_shapes() {
+    for vertices in [&NOTCH[..], &EXAMPLE, &SPIRAL, &L] {
+        let polygon = RectilinearPolygon::new(vertices).unwrap();
+
+        for x in -1..=13 {
+            for y in -1..=13 {
+                assert_eq!(polygon.contains((x, y)), naive_contains(vertices, (x, y)), "{x},{y} in {vertices:?}");
+            }
+        }
+
+        check(300, |rng| {
+            let mut corner = || (rng.below(15) as i64 - 1, rng.below(15) as i64 - 1);
+            let (a, b) = (corner(), corner());
+
+            assert_eq!(
+                polygon.contains_rectangle(a, b),
+                naive_contains_rectangle(vertices, a, b),
+                "{a:?} to {b:?} in {vertices:?}",
+            );
+        });
+    }
+}
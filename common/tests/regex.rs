@@ -0,0 +1,122 @@
+use aoc_2025_common::*;
+use std::sync::LazyLock;
+
+/// `x,y`, parsed by hand rather than with the derive, so these tests only depend on the common crate
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+impl FromRegexCaptures for Point {
+    fn from_regex_captures(captures: &regex::Captures) -> Result<Self, FromRegexCapturesError> {
+        let field = |name| {
+            let m = captures.name(name).ok_or(FromRegexCapturesError::MissingField(name))?;
+            m.as_str().parse().map_err(|e| FromRegexCapturesError::failed_to_parse(name, &m, e))
+        };
+
+        Ok(Point { x: field("x")?, y: field("y")? })
+    }
+}
+
+impl FromRegex for Point {
+    fn regex() -> &'static regex::Regex {
+        static REGEX: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new("(?<x>[0-9]+),(?<y>[0-9]+)").unwrap());
+        &REGEX
+    }
+}
+
+fn point(x: u8, y: u8) -> Point { Point { x, y } }
+
+#[test]
+fn finds_every_match() {
+    let points: Vec<Point> = "1,2 3,4\n5,6".iter_by_regex(Point::regex()).collect();
+    assert_eq!(points, [point(1, 2), point(3, 4), point(5, 6)]);
+}
+
+#[test]
+fn skips_text_between_matches() {
+    let points: Vec<Point> = "a 1,2 b; 3,4 c".iter_by_regex(Point::regex()).collect();
+    assert_eq!(points, [point(1, 2), point(3, 4)]);
+}
+
+#[test]
+fn reports_where_a_match_failed_to_parse() {
+    let results: Vec<Result<Point, ParseError>> = "1,2\n3,4 5,999\n7,8".try_iter_by_regex(Point::regex()).collect();
+
+    assert_eq!(results.len(), 4);
+    let error = results[2].as_ref().unwrap_err();
+    assert_eq!((error.line, error.column), (2, 7));
+    assert_eq!(error.matched, "5,999");
+    assert!(matches!(&error.error, FromRegexCapturesError::FailedToParse { field: "y", text, .. } if text == "999"));
+    assert!(results[3].is_ok(), "parsing carries on after an error");
+}
+
+#[test]
+fn iter_by_regex_stops_at_the_first_error() {
+    let points: Vec<Point> = "1,2 3,999 5,6".iter_by_regex(Point::regex()).collect();
+    assert_eq!(points, [point(1, 2)]);
+}
+
+#[test]
+fn steps_over_empty_matches() {
+    let regex = regex::Regex::new("(?<x>[0-9]*)").unwrap();
+
+    struct Digits(String);
+    impl FromRegexCaptures for Digits {
+        fn from_regex_captures(captures: &regex::Captures) -> Result<Self, FromRegexCapturesError> {
+            Ok(Digits(captures["x"].to_string()))
+        }
+    }
+
+    let matches: Vec<String> = "12a3".iter_by_regex(&regex).map(|Digits(digits)| digits).collect();
+    assert_eq!(matches, ["12", "", "3", ""]);
+}
+
+#[test]
+fn strict_parsing_allows_separators() {
+    let points: Result<Vec<Point>, _> = " 1,2, 3,4,\n5,6 ".try_iter_by_regex_strict(Point::regex(), |c| c == ',' || c.is_whitespace()).collect();
+    assert_eq!(points.unwrap(), [point(1, 2), point(3, 4), point(5, 6)]);
+}
+
+#[test]
+fn strict_parsing_reports_unmatched_input() {
+    let results: Vec<Result<Point, ParseError>> = "1,2\n3,4 x5,6 7,8".try_iter_by_regex_strict(Point::regex(), char::is_whitespace).collect();
+
+    assert_eq!(results.len(), 3, "parsing stops at unmatched input");
+    let error = results[2].as_ref().unwrap_err();
+    assert!(matches!(error.error, FromRegexCapturesError::UnmatchedInput));
+    assert_eq!((error.line, error.column), (2, 5));
+    assert_eq!(error.matched, "x");
+}
+
+#[test]
+fn parse_exact_needs_the_whole_string() {
+    assert_eq!(Point::parse_exact("1,2").unwrap(), point(1, 2));
+    assert!(matches!(Point::parse_exact("1,2 "), Err(FromRegexCapturesError::RegexDidntMatch)));
+    assert!(matches!(Point::parse_exact("x"), Err(FromRegexCapturesError::RegexDidntMatch)));
+}
+
+#[test]
+fn input_errors_count_lines_already_read() {
+    let mut input = Input::new("header\n1,2\n3,x\n4,999\n".to_string());
+    assert_eq!(input.lines().next().as_deref(), Some("header"));
+
+    let results: Vec<Result<Point, ParseError>> = input.try_iter_by_regex(Point::regex()).collect();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[1].as_ref().unwrap_err().line, 4);
+}
+
+#[test]
+fn sections_count_lines_before_them() {
+    let mut sections = Input::new("1,2\n\n\n3,4\n5,999\n".to_string()).sections();
+    assert_eq!(sections.len(), 2);
+
+    assert_eq!(*Point::parse_section(&mut sections, 0).unwrap(), [point(1, 2)]);
+
+    let Err(SectionError::Parse(error)) = Point::parse_section(&mut sections, 1) else { panic!("expected a parse error") };
+    assert_eq!((error.line, error.column), (5, 3));
+
+    assert!(matches!(sections.take(1), Err(SectionError::AlreadyTaken(1))));
+    assert!(matches!(sections.take(2), Err(SectionError::Missing { index: 2, count: 2 })));
+}
@@ -0,0 +1,82 @@
+mod support;
+
+use aoc_2025_common::{DisjointSet, KdTree};
+use support::{Rng, check};
+
+/// Points on a small grid, so there are plenty of equal distances
+fn random_points(rng: &mut Rng) -> Vec<[i64; 3]> {
+    let coordinates = rng.vec(90, 6);
+    coordinates.chunks_exact(3).map(|point| [point[0] as i64, point[1] as i64, point[2] as i64]).collect()
+}
+
+/// Every unique pair, sorted by distance and then index
+fn naive_closest_pairs(points: &[[i64; 3]]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<_> = (0..points.len())
+        .flat_map(|lhs| (lhs + 1..points.len()).map(move |rhs| (lhs, rhs)))
+        .collect();
+    pairs.sort_by_key(|&(lhs, rhs)| (KdTree::sqr_distance(&points[lhs], &points[rhs]), lhs, rhs));
+    pairs
+}
+
+#[test]
+fn nearest_skips_the_point_itself() {
+    let tree = KdTree::new([[0, 0], [5, 0], [1, 1], [0, 2]]);
+    assert_eq!(tree.nearest(0, 2), [(2, 2), (4, 3)]);
+    assert_eq!(tree.nearest(1, 10), [(17, 2), (25, 0), (29, 3)]);
+    assert_eq!(tree.nearest(1, 0), []);
+}
+
+#[test]
+fn closest_pairs_of_few_points() {
+    assert_eq!(KdTree::<2>::new([]).closest_pairs().count(), 0);
+    assert_eq!(KdTree::new([[3]]).closest_pairs().count(), 0);
+    assert_eq!(KdTree::new([[0], [10], [4]]).closest_pairs().collect::<Vec<_>>(), [(0, 2), (1, 2), (0, 1)]);
+}
+
+#[test]
+fn nearest_matches_naive() {
+    check(100, |rng| {
+        let points = random_points(rng);
+        let tree = KdTree::new(points.iter().copied());
+
+        for index in 0..points.len() {
+            let k = rng.below(points.len() + 1);
+            let mut expected: Vec<_> = (0..points.len())
+                .filter(|&other| other != index)
+                .map(|other| (KdTree::sqr_distance(&points[index], &points[other]), other))
+                .collect();
+            expected.sort();
+            expected.truncate(k);
+
+            assert_eq!(tree.nearest(index, k), expected, "{k} nearest to {index} in {points:?}");
+        }
+    });
+}
+
+#[test]
+fn closest_pairs_match_naive() {
+    check(100, |rng| {
+        let points = random_points(rng);
+        let tree = KdTree::new(points.iter().copied());
+
+        assert_eq!(tree.closest_pairs().collect::<Vec<_>>(), naive_closest_pairs(&points), "{points:?}");
+    });
+}
+
+#[test]
+fn minimum_spanning_tree_matches_naive_kruskal() {
+    check(100, |rng| {
+        let points = random_points(rng);
+        let tree = KdTree::new(points.iter().copied());
+
+        let mut sets = DisjointSet::new(points.len());
+        let expected: Vec<_> = naive_closest_pairs(&points).into_iter()
+            .filter(|&(lhs, rhs)| sets.union(lhs, rhs))
+            .collect();
+
+        let mut spanning_tree = tree.minimum_spanning_tree();
+        assert_eq!(spanning_tree.by_ref().collect::<Vec<_>>(), expected, "{points:?}");
+        assert!(spanning_tree.sets().components() <= 1);
+        assert_eq!(expected.len(), points.len().saturating_sub(1));
+    });
+}
@@ -0,0 +1,42 @@
+//! Helpers shared by the property tests
+#![allow(dead_code)] // not every test uses every helper
+
+/// A small xorshift generator, so the property tests are reproducible and don't need another crate
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck on 0
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..bound`
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A vector of up to `max_len` numbers in `0..max_value`
+    pub fn vec(&mut self, max_len: usize, max_value: usize) -> Vec<usize> {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| self.below(max_value)).collect()
+    }
+}
+
+/// Runs `property` with a differently seeded generator for each case. The seed is in the panic message of a
+/// failing case, so it can be reproduced
+pub fn check(cases: u64, mut property: impl FnMut(&mut Rng)) {
+    for seed in 0..cases {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| property(&mut Rng::new(seed))));
+        if let Err(e) = result {
+            eprintln!("property failed with seed {seed}");
+            std::panic::resume_unwind(e);
+        }
+    }
+}
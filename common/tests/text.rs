@@ -0,0 +1,50 @@
+use aoc_2025_common::*;
+
+#[test]
+fn fixed_width_text_splits_on_blank_columns() {
+    let text = text::pad_lines("12  3\n 4  56\n");
+    assert_eq!(text.to_string(), "12  3 \n 4  56\n");
+
+    let blocks = text::column_blocks(&text);
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(text::horizontal_numbers::<u32>(&blocks[0]).unwrap(), [12, 4]);
+    assert_eq!(text::vertical_numbers::<u32>(&blocks[0]).unwrap(), [1, 24]);
+    assert_eq!(text::vertical_numbers::<u32>(&blocks[1]).unwrap(), [35, 6]);
+}
+
+#[test]
+fn ragged_lines_are_padded_to_the_longest() {
+    let text = text::pad_lines("1\n\n123\n  4");
+    assert_eq!((text.width(), text.height()), (3, 4));
+    assert_eq!(text.to_string(), "1  \n   \n123\n  4\n");
+
+    // the short lines don't end the block early, and the blank line is skipped
+    let blocks = text::column_blocks(&text);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(text::horizontal_numbers::<u32>(&blocks[0]).unwrap(), [1, 123, 4]);
+    assert_eq!(text::vertical_numbers::<u32>(&blocks[0]).unwrap(), [11, 2, 34]);
+}
+
+#[test]
+fn empty_text_has_no_blocks() {
+    let text = text::pad_lines("");
+    assert_eq!((text.width(), text.height()), (0, 0));
+    assert!(text::column_blocks(&text).is_empty());
+}
+
+#[test]
+fn blocks_without_an_operator_row_are_all_numbers() {
+    let blocks = text::column_blocks(&text::pad_lines("12 3\n45 6\n"));
+
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(text::horizontal_numbers::<u32>(&blocks[0]).unwrap(), [12, 45]);
+    assert_eq!(text::vertical_numbers::<u32>(&blocks[1]).unwrap(), [36]);
+}
+
+#[test]
+fn operators_left_in_a_block_fail_to_parse() {
+    let blocks = text::column_blocks(&text::pad_lines("12\n3 \n+ \n"));
+
+    assert_eq!(blocks.len(), 1);
+    assert!(text::horizontal_numbers::<u32>(&blocks[0]).is_err());
+}
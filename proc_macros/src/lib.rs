@@ -1,38 +1,341 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 
-#[proc_macro_derive(FromRegexCaptures)]
+/// Derives `FromRegexCaptures`:
+/// - structs with named fields parse each field from the capture group with the same name
+/// - tuple structs parse each field from the positional capture group, starting at 1
+/// - enums pick the first variant whose capture group participated in the match. The group defaults to the
+///   variant's name, or can be given with `#[regex_capture("name")]`. The variant's fields are then parsed as for structs
+///
+/// Fields are parsed with `FromStr` by default, and can be configured with `#[capture(...)]`:
+/// - `name = "group"` reads the field from a differently named capture group
+/// - `default` uses `Default::default()` if the group didn't participate in the match
+/// - `with = path::to::fn` parses the group with a `fn(&str) -> Result<T, E>` instead of `FromStr`, where `E` can be
+///   boxed into a `dyn Error`
+/// - `flatten` parses the field with its own `FromRegexCaptures` impl, from the same captures
+///
+/// `Option<T>` fields parse a `T`, and are `None` if their group didn't participate in the match
+///
+/// `#[from_regex(r"...")]` on the type also implements `FromRegex` with a lazily compiled copy of the regex, and
+/// `FromStr` using it. The regex is checked at compile time, along with every capture group the fields read from
+///
+/// ```
+/// use aoc_2025_proc_macros::FromRegexCaptures;
+///
+/// #[derive(FromRegexCaptures)]
+/// #[from_regex("(?<x>[0-9]+),(?<y>[0-9]+)")]
+/// struct Point {
+///     x: i32,
+///     #[capture(name = "y")]
+///     height: i32,
+/// }
+///
+/// let point: Point = "3,4".parse().unwrap();
+/// assert_eq!((point.x, point.height), (3, 4));
+/// ```
+///
+/// Reading a group that the regex doesn't have is a compile error:
+///
+/// ```compile_fail
+/// use aoc_2025_proc_macros::FromRegexCaptures;
+///
+/// #[derive(FromRegexCaptures)]
+/// #[from_regex("(?<x>[0-9]+),(?<y>[0-9]+)")]
+/// struct Point {
+///     x: i32,
+///     #[capture(name = "why")]
+///     height: i32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use aoc_2025_proc_macros::FromRegexCaptures;
+///
+/// #[derive(FromRegexCaptures)]
+/// #[from_regex("([0-9]+),([0-9]+)")]
+/// struct Point(i32, i32, i32);
+/// ```
+#[proc_macro_derive(FromRegexCaptures, attributes(regex_capture, capture, from_regex))]
 pub fn derive_from_regex_captures(ts: TokenStream) -> TokenStream {
     // let syn parse the input stream
     let input = syn::parse_macro_input!(ts as syn::DeriveInput);
 
+    match derive(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream2> {
     // the type we're deriving the trait for
-    let struct_type = input.ident;
+    let struct_type = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut groups = Vec::new();
+
+    let body = match &input.data {
+        syn::Data::Struct(data) => {
+            let constructor = construct(quote::quote! { Self }, &data.fields, &mut groups)?;
+            quote::quote! { Ok(#constructor) }
+        },
+        syn::Data::Enum(data) => derive_enum(input, data, &mut groups)?,
+        syn::Data::Union(_) => return Err(syn::Error::new_spanned(input, "FromRegexCaptures can't be derived for unions")),
+    };
+
+    let mut result = quote::quote! {
+        impl #impl_generics aoc_2025_common::FromRegexCaptures for #struct_type #type_generics #where_clause {
+            fn from_regex_captures(captures: &regex::Captures) -> Result<Self, aoc_2025_common::FromRegexCapturesError> {
+                use aoc_2025_common::FromRegexCapturesError;
+                #body
+            }
+        }
+    };
+
+    if let Some(pattern) = container_regex(input)? {
+        check_groups(&pattern, &groups)?;
+
+        result.extend(quote::quote! {
+            impl #impl_generics aoc_2025_common::FromRegex for #struct_type #type_generics #where_clause {
+                fn regex() -> &'static regex::Regex {
+                    static REGEX: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| regex::Regex::new(#pattern).unwrap());
+                    &REGEX
+                }
+            }
+
+            impl #impl_generics std::str::FromStr for #struct_type #type_generics #where_clause {
+                type Err = aoc_2025_common::FromRegexCapturesError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    <Self as aoc_2025_common::FromRegex>::parse_exact(s)
+                }
+            }
+        });
+    }
+
+    Ok(result)
+}
+
+/// A capture group that the generated code reads from, and the field or variant that reads it
+struct GroupUse {
+    group: Group,
+    span: proc_macro2::Span,
+}
+
+enum Group {
+    Named(String),
+    Index(usize),
+}
+
+/// The pattern from `#[from_regex(r"...")]`, if the type has one
+fn container_regex(input: &syn::DeriveInput) -> syn::Result<Option<syn::LitStr>> {
+    let mut attrs = input.attrs.iter().filter(|attr| attr.path().is_ident("from_regex"));
+
+    let Some(attr) = attrs.next() else { return Ok(None) };
+
+    if let Some(duplicate) = attrs.next() {
+        return Err(syn::Error::new_spanned(duplicate, "a type can only have one #[from_regex(...)] attribute"));
+    }
+
+    attr.parse_args::<syn::LitStr>()
+        .map(Some)
+        .map_err(|e| syn::Error::new(e.span(), "expected a regex, like #[from_regex(r\"...\")]"))
+}
+
+/// Compiles the regex, and checks that it has every group that's read from
+fn check_groups(pattern: &syn::LitStr, groups: &[GroupUse]) -> syn::Result<()> {
+    let regex = regex::Regex::new(&pattern.value())
+        .map_err(|e| syn::Error::new_spanned(pattern, format!("invalid regex: {e}")))?;
+
+    let mut errors = groups.iter()
+        .filter_map(|GroupUse { group, span }| match group {
+            Group::Named(name) if !regex.capture_names().any(|n| n == Some(name.as_str())) =>
+                Some(syn::Error::new(*span, format!("the regex has no capture group named `{name}`"))),
+            Group::Index(index) if *index >= regex.captures_len() =>
+                Some(syn::Error::new(*span, format!("the regex has no capture group {index}"))),
+            _ => None,
+        });
+
+    // report every missing group at once
+    let Some(mut error) = errors.next() else { return Ok(()) };
+    errors.for_each(|e| error.combine(e));
+    Err(error)
+}
+
+fn derive_enum(input: &syn::DeriveInput, data: &syn::DataEnum, groups: &mut Vec<GroupUse>) -> syn::Result<TokenStream2> {
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(input, "FromRegexCaptures can't be derived for enums without variants"));
+    }
+
+    let mut body = quote::quote! {};
+    let mut group_names = Vec::new();
+
+    for variant in &data.variants {
+        let variant_name = &variant.ident;
+        let group_name = variant_group_name(variant)?;
+        let constructor = construct(quote::quote! { Self::#variant_name }, &variant.fields, groups)?;
+
+        body.extend(quote::quote! {
+            if captures.name(#group_name).is_some() {
+                return Ok(#constructor);
+            }
+        });
+
+        groups.push(GroupUse { group: Group::Named(group_name.value()), span: group_name.span() });
+        group_names.push(group_name.value());
+    }
+
+    // none of the variants matched, so report every group we were looking for
+    let missing = group_names.join(" or ");
+    body.extend(quote::quote! {
+        Err(FromRegexCapturesError::MissingField(#missing))
+    });
 
-    match &input.data {
-        // we're only deriving this trait for structs of named fields
-        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named( fields ), .. }) => {
+    Ok(body)
+}
+
+/// The capture group that selects this variant, from `#[regex_capture("name")]` or the variant's name
+fn variant_group_name(variant: &syn::Variant) -> syn::Result<syn::LitStr> {
+    let mut attrs = variant.attrs.iter().filter(|attr| attr.path().is_ident("regex_capture"));
+
+    let Some(attr) = attrs.next() else {
+        return Ok(syn::LitStr::new(&variant.ident.to_string(), variant.ident.span()));
+    };
+
+    if let Some(duplicate) = attrs.next() {
+        return Err(syn::Error::new_spanned(duplicate, "a variant can only have one #[regex_capture(...)] attribute"));
+    }
+
+    attr.parse_args::<syn::LitStr>()
+        .map_err(|e| syn::Error::new(e.span(), "expected a capture group name, like #[regex_capture(\"name\")]"))
+}
+
+/// How a field is read from the captures, from its `#[capture(...)]` attributes
+#[derive(Default)]
+struct FieldOptions {
+    name: Option<syn::LitStr>,
+    default: bool,
+    with: Option<syn::ExprPath>,
+    flatten: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("capture")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    options.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    options.default = true;
+                } else if meta.path.is_ident("with") {
+                    options.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("flatten") {
+                    options.flatten = true;
+                } else {
+                    return Err(meta.error("expected one of `name = \"...\"`, `default`, `with = path` or `flatten`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if options.flatten && (options.name.is_some() || options.with.is_some() || options.default) {
+            return Err(syn::Error::new_spanned(field, "`flatten` can't be combined with other capture options"));
+        }
+
+        Ok(options)
+    }
+}
+
+/// Whether the field is an `Option<T>`, which is `None` rather than an error when its group didn't match
+fn is_option(ty: &syn::Type) -> bool {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = ty else { return false };
+    path.segments.last().is_some_and(|segment| segment.ident == "Option")
+}
+
+/// Builds `path` from the captures, reading each field as configured by its `#[capture(...)]` attributes
+fn construct(path: TokenStream2, fields: &syn::Fields, groups: &mut Vec<GroupUse>) -> syn::Result<TokenStream2> {
+    match fields {
+        syn::Fields::Named(fields) => {
             let mut members = quote::quote! {};
 
             for field in &fields.named {
                 let field_name = field.ident.as_ref().unwrap();
-                members.extend(quote::quote! {
-                    #field_name: captures.name(stringify!(#field_name))
-                        .ok_or(FromRegexCapturesError::MissingField(stringify!(#field_name)))?
-                        .as_str().parse()
-                        .map_err(|e| FromRegexCapturesError::FailedToParse(stringify!(#field_name)))?,
-                });
+                let options = FieldOptions::parse(field)?;
+                let group_name = options.name.clone()
+                    .unwrap_or_else(|| syn::LitStr::new(&field_name.to_string(), field_name.span()));
+                let value = read_field(field, &options, quote::quote! { captures.name(#group_name) }, &group_name);
+                members.extend(quote::quote! { #field_name: #value, });
+
+                if !options.flatten {
+                    groups.push(GroupUse { group: Group::Named(group_name.value()), span: group_name.span() });
+                }
             }
 
-            quote::quote! {
-                impl aoc_2025_common::FromRegexCaptures for #struct_type {
-                    fn from_regex_captures(captures: &regex::Captures) -> Result<Self, aoc_2025_common::FromRegexCapturesError> {
-                        use aoc_2025_common::FromRegexCapturesError;
-                        Ok(Self { #members })
-                    }
+            Ok(quote::quote! { #path { #members } })
+        },
+        syn::Fields::Unnamed(fields) => {
+            let mut members = quote::quote! {};
+
+            for (index, field) in fields.unnamed.iter().enumerate() {
+                let options = FieldOptions::parse(field)?;
+
+                let span = syn::spanned::Spanned::span(field);
+
+                let (value, group) = match &options.name {
+                    Some(group_name) => (
+                        read_field(field, &options, quote::quote! { captures.name(#group_name) }, group_name),
+                        Group::Named(group_name.value()),
+                    ),
+                    None => {
+                        // group 0 is the whole match, so the fields start at group 1
+                        let group_index = syn::Index::from(index + 1);
+                        let group_name = syn::LitStr::new(&(index + 1).to_string(), span);
+                        (
+                            read_field(field, &options, quote::quote! { captures.get(#group_index) }, &group_name),
+                            Group::Index(index + 1),
+                        )
+                    },
+                };
+
+                if !options.flatten {
+                    groups.push(GroupUse { group, span });
                 }
+
+                members.extend(quote::quote! { #value, });
             }
+
+            Ok(quote::quote! { #path ( #members ) })
         },
-        _ => unimplemented!()
-    }.into()
+        syn::Fields::Unit => Ok(path),
+    }
+}
+
+fn read_field(field: &syn::Field, options: &FieldOptions, group: TokenStream2, group_name: &syn::LitStr) -> TokenStream2 {
+    if options.flatten {
+        let ty = &field.ty;
+        return quote::quote! { <#ty as aoc_2025_common::FromRegexCaptures>::from_regex_captures(captures)? };
+    }
+
+    let parse = match &options.with {
+        Some(with) => quote::quote! { #with(m.as_str()) },
+        None => quote::quote! { m.as_str().parse() },
+    };
+
+    let parsed = quote::quote! { #parse.map_err(|e| FromRegexCapturesError::failed_to_parse(#group_name, &m, e))? };
+
+    let missing = if is_option(&field.ty) {
+        quote::quote! { None }
+    } else if options.default {
+        quote::quote! { Default::default() }
+    } else {
+        quote::quote! { return Err(FromRegexCapturesError::MissingField(#group_name)) }
+    };
+
+    let parsed = if is_option(&field.ty) { quote::quote! { Some(#parsed) } } else { parsed };
+
+    quote::quote! {
+        match #group { Some(m) => #parsed, None => #missing }
+    }
 }